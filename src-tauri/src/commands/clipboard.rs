@@ -1,3 +1,5 @@
+use crate::error::GrovrError;
+
 /// Reads clipboard text safely by dispatching to the main thread.
 ///
 /// The `arboard` crate (used by tauri-plugin-clipboard-manager) accesses NSPasteboard
@@ -10,7 +12,7 @@
 /// By using `app.run_on_main_thread()` we ensure NSPasteboard is only accessed from
 /// the main thread, which is what Apple requires.
 #[tauri::command]
-pub async fn read_clipboard_text(app: tauri::AppHandle) -> Result<String, String> {
+pub async fn read_clipboard_text(app: tauri::AppHandle) -> Result<String, GrovrError> {
     let (tx, rx) = tokio::sync::oneshot::channel();
 
     app.run_on_main_thread(move || {
@@ -21,6 +23,8 @@ pub async fn read_clipboard_text(app: tauri::AppHandle) -> Result<String, String
     })
     .map_err(|e| e.to_string())?;
 
-    rx.await
-        .map_err(|_| "Failed to receive clipboard result".to_string())?
+    let result = rx
+        .await
+        .map_err(|_| "Failed to receive clipboard result".to_string())?;
+    Ok(result?)
 }
@@ -1,14 +1,49 @@
-use git2::{BranchType, Repository};
+use crate::commands::integrations::{self, PullRequestHead};
+use crate::commands::operations::{Operation, OperationKind};
+use crate::commands::settings::{save_settings, SettingsState};
+use crate::error::GrovrError;
+use crate::types::{ArchivedWorktree, CopyMode, CopyPathSpec, WorktreeMemo};
+use git2::{BranchType, Repository, WorktreeLockStatus};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
 use std::process::Command;
-
-#[derive(Debug, Serialize, Deserialize, Clone)]
+use std::sync::Arc;
+use std::time::{Duration, UNIX_EPOCH};
+use tauri::State;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use walkdir::WalkDir;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Worktree {
     pub path: String,
+    /// Checked-out branch, empty when HEAD is detached
     pub branch: String,
     pub is_main: bool,
     pub is_bare: bool,
+    /// Commit sha HEAD resolves to, `None` for an unborn branch
+    pub head: Option<String>,
+    pub is_detached: bool,
+    pub is_locked: bool,
+    pub lock_reason: Option<String>,
+    /// Set when the worktree directory is gone and `git worktree prune` would remove it
+    pub is_prunable: bool,
+    pub prunable_reason: Option<String>,
+    /// Administrative directory (`.git` for the main worktree, `.git/worktrees/<name>` otherwise)
+    pub gitdir: String,
+    /// Unix timestamp of when the worktree was added, if the filesystem records it
+    pub created_at: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WorktreeRelocation {
+    pub from: String,
+    pub to: String,
+    pub moved: bool,
+    pub error: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -24,95 +59,298 @@ pub struct WorktreeStatus {
     pub staged: i32,
     pub unstaged: i32,
     pub untracked: i32,
+    /// Upstream of the checked-out branch, e.g. `origin/feature`
+    pub upstream: Option<String>,
+    /// Upstream is configured but its remote-tracking branch no longer exists
+    pub upstream_gone: bool,
+    pub ahead: Option<usize>,
+    pub behind: Option<usize>,
+    /// Project default branch the `*_base` counts are relative to
+    pub base_branch: Option<String>,
+    pub ahead_of_base: Option<usize>,
+    pub behind_base: Option<usize>,
+    pub last_commit: Option<CommitInfo>,
 }
 
-// ============ Worktree Commands ============
+/// Per-worktree entry of `get_project_status`: either a status or the error computing it
+#[derive(Debug, Serialize)]
+pub struct WorktreeStatusResult {
+    pub status: Option<WorktreeStatus>,
+    pub error: Option<GrovrError>,
+}
 
-#[tauri::command]
-pub fn get_worktrees(repo_path: String) -> Result<Vec<Worktree>, String> {
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CommitInfo {
+    pub sha: String,
+    pub summary: String,
+    pub author: String,
+    /// Unix timestamp of the commit
+    pub time: i64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct StashEntry {
+    pub index: usize,
+    pub message: String,
+}
+
+/// What removing a worktree and its branch would throw away.
+#[derive(Debug, Serialize, Clone)]
+pub struct RemovalPlan {
+    /// Fingerprint of the plan; `remove_worktree` accepts it as acknowledgement
+    /// only while nothing below has changed
+    pub plan_id: String,
+    pub path: String,
+    pub branch: Option<String>,
+    /// Modified, staged and untracked files a forced removal discards
+    pub dirty_files: Vec<String>,
+    /// Most recent commits on the branch that are not on any remote-tracking branch
+    pub unpushed_commits: Vec<CommitInfo>,
+    pub unpushed_count: usize,
+    /// Stash entries created on the branch
+    pub stashes: Vec<StashEntry>,
+    /// Whether the branch is merged into `base_branch`, `None` if unknown
+    pub branch_merged: Option<bool>,
+    pub base_branch: Option<String>,
+}
+
+impl RemovalPlan {
+    fn loses_work(&self, force: bool, delete_branch: bool) -> bool {
+        (force && !self.dirty_files.is_empty()) || (delete_branch && self.unpushed_count > 0)
+    }
+}
+
+/// Which checks `find_stale_worktrees` runs. Integration checks are off unless requested
+/// since they hit the GitHub/Jira APIs once per worktree.
+#[derive(Debug, Deserialize, Default)]
+pub struct StaleCriteria {
+    #[serde(default)]
+    pub merged: bool,
+    #[serde(default)]
+    pub upstream_gone: bool,
+    /// Flag worktrees whose last commit is older than this many days
+    #[serde(default)]
+    pub inactive_days: Option<u64>,
+    #[serde(default)]
+    pub pr_closed: bool,
+    #[serde(default)]
+    pub jira_done: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StaleReason {
+    /// Branch tip is reachable from the default branch
+    Merged { base_branch: String },
+    UpstreamGone { upstream: String },
+    Inactive { days: u64 },
+    PrClosed { number: i32, merged: bool, url: String },
+    JiraDone { key: String, status: String },
+}
+
+#[derive(Debug, Serialize)]
+pub struct StaleWorktree {
+    pub worktree: Worktree,
+    pub reasons: Vec<StaleReason>,
+    /// Included so the UI can warn before removing worktrees with local changes
+    pub status: Option<WorktreeStatus>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WorktreeCleanup {
+    pub path: String,
+    pub branch: Option<String>,
+    pub removed: bool,
+    pub error: Option<GrovrError>,
+}
+
+// ============ Helpers ============
+
+/// Runs `git` in `dir` and returns its stdout, mapping failures to a typed error.
+fn run_git(dir: &str, args: &[&str]) -> Result<String, GrovrError> {
+    run_git_env(dir, args, &[])
+}
+
+fn run_git_env(dir: &str, args: &[&str], envs: &[(&str, &str)]) -> Result<String, GrovrError> {
     let output = Command::new("git")
-        .args(["worktree", "list", "--porcelain"])
-        .current_dir(&repo_path)
+        .args(args)
+        .envs(envs.iter().copied())
+        .current_dir(dir)
         .output()
-        .map_err(|e| format!("Failed to run git: {}", e))?;
+        .map_err(|e| GrovrError::from_spawn(dir, e))?;
 
     if !output.status.success() {
-        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        return Err(GrovrError::from_git_stderr(dir, &String::from_utf8_lossy(&output.stderr)));
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut worktrees = Vec::new();
-    let mut current_path: Option<String> = None;
-    let mut current_branch: Option<String> = None;
-    let mut is_bare = false;
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
 
-    for line in stdout.lines() {
-        if line.starts_with("worktree ") {
-            // Save previous worktree if exists
-            if let Some(path) = current_path.take() {
-                worktrees.push(Worktree {
-                    path: path.clone(),
-                    branch: current_branch.take().unwrap_or_default(),
-                    is_main: worktrees.is_empty(),
-                    is_bare,
-                });
-                is_bare = false;
-            }
-            current_path = Some(line.strip_prefix("worktree ").unwrap().to_string());
-        } else if line.starts_with("branch ") {
-            let branch = line.strip_prefix("branch refs/heads/").unwrap_or(
-                line.strip_prefix("branch ").unwrap_or("")
-            );
-            current_branch = Some(branch.to_string());
-        } else if line == "bare" {
-            is_bare = true;
-        }
-    }
+pub(crate) fn open_repo(path: &str) -> Result<Repository, GrovrError> {
+    Repository::open(path).map_err(|_| GrovrError::NotAGitRepo { path: path.to_string() })
+}
 
-    // Don't forget the last one
-    if let Some(path) = current_path {
-        worktrees.push(Worktree {
-            path,
-            branch: current_branch.unwrap_or_default(),
-            is_main: worktrees.is_empty(),
-            is_bare,
+// ============ Worktree Commands ============
+
+#[tauri::command]
+pub fn get_worktrees(repo_path: String) -> Result<Vec<Worktree>, GrovrError> {
+    let repo = open_repo(&repo_path)?;
+
+    // Worktrees are tracked in the common dir; when repo_path is itself a linked
+    // worktree, reopen the main repository to learn its workdir and bare state
+    let common_dir = common_dir(&repo);
+    let main_repo = if repo.is_worktree() {
+        Repository::open(&common_dir)?
+    } else {
+        repo
+    };
+
+    let main_path = if main_repo.is_bare() {
+        common_dir.clone()
+    } else {
+        main_repo.workdir().map(Path::to_path_buf).unwrap_or_else(|| common_dir.clone())
+    };
+    let head = read_head(&main_repo, &common_dir);
+
+    let mut worktrees = vec![Worktree {
+        path: display_path(&main_path),
+        branch: head.branch,
+        is_main: true,
+        is_bare: main_repo.is_bare(),
+        head: head.sha,
+        is_detached: head.detached,
+        is_locked: false,
+        lock_reason: None,
+        is_prunable: false,
+        prunable_reason: None,
+        gitdir: display_path(&common_dir),
+        created_at: None,
+    }];
+
+    let mut linked = Vec::new();
+    for name in main_repo.worktrees()?.iter().flatten() {
+        let wt = main_repo.find_worktree(name)?;
+        let gitdir = common_dir.join("worktrees").join(name);
+        let head = read_head(&main_repo, &gitdir);
+
+        let (is_locked, lock_reason) = match wt.is_locked() {
+            Ok(WorktreeLockStatus::Locked(reason)) => (
+                true,
+                reason.map(|r| r.trim().to_string()).filter(|r| !r.is_empty()),
+            ),
+            _ => (false, None),
+        };
+
+        // Like git, never report a locked worktree as prunable
+        let prunable_reason = if is_locked {
+            None
+        } else {
+            wt.validate().err().map(|e| e.message().to_string())
+        };
+
+        linked.push(Worktree {
+            path: wt.path().to_string_lossy().to_string(),
+            branch: head.branch,
+            is_main: false,
+            is_bare: false,
+            head: head.sha,
+            is_detached: head.detached,
+            is_locked,
+            lock_reason,
+            is_prunable: prunable_reason.is_some(),
+            prunable_reason,
+            gitdir: gitdir.to_string_lossy().to_string(),
+            created_at: created_at(&gitdir),
         });
     }
 
+    // libgit2 returns worktrees in directory order; keep the list stable
+    linked.sort_by(|a, b| a.path.cmp(&b.path));
+    worktrees.extend(linked);
+
     Ok(worktrees)
 }
 
+/// Returns the repository's common dir, the `.git` directory shared by all worktrees.
+pub(crate) fn common_dir(repo: &Repository) -> PathBuf {
+    let git_dir = repo.path();
+    let common = match std::fs::read_to_string(git_dir.join("commondir")) {
+        Ok(relative) => git_dir.join(relative.trim()),
+        Err(_) => git_dir.to_path_buf(),
+    };
+    std::fs::canonicalize(&common).unwrap_or(common)
+}
+
+struct HeadInfo {
+    branch: String,
+    sha: Option<String>,
+    detached: bool,
+}
+
+/// Reads a worktree's HEAD straight from its admin dir so that worktrees whose
+/// directory has disappeared can still be described.
+fn read_head(repo: &Repository, gitdir: &Path) -> HeadInfo {
+    let content = std::fs::read_to_string(gitdir.join("HEAD")).unwrap_or_default();
+    let content = content.trim();
+
+    if let Some(refname) = content.strip_prefix("ref: ") {
+        HeadInfo {
+            branch: refname.strip_prefix("refs/heads/").unwrap_or(refname).to_string(),
+            sha: repo.refname_to_id(refname).ok().map(|oid| oid.to_string()),
+            detached: false,
+        }
+    } else {
+        HeadInfo {
+            branch: String::new(),
+            sha: Some(content.to_string()).filter(|s| !s.is_empty()),
+            detached: !content.is_empty(),
+        }
+    }
+}
+
+fn created_at(path: &Path) -> Option<i64> {
+    let created = std::fs::metadata(path).and_then(|m| m.created()).ok()?;
+    created.duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs() as i64)
+}
+
+/// Formats a path the way `git worktree list` does: absolute, symlinks resolved,
+/// no trailing separator. Worktree memos are keyed by this string.
+fn display_path(path: &Path) -> String {
+    let path = std::fs::canonicalize(path).unwrap_or_else(|_| PathBuf::from(path));
+    let path = path.to_string_lossy();
+    let path = path.strip_prefix(r"\\?\").unwrap_or(&path);
+    path.trim_end_matches(['/', '\\']).to_string()
+}
+
 #[tauri::command]
 pub async fn create_worktree(
+    app: tauri::AppHandle,
     repo_path: String,
     worktree_path: String,
     branch_name: String,
     base_branch: String,
-) -> Result<(), String> {
-    tokio::task::spawn_blocking(move || {
-        let output = Command::new("git")
-            .args(["worktree", "add", "-b", &branch_name, &worktree_path, &base_branch])
-            .current_dir(&repo_path)
-            .output()
-            .map_err(|e| format!("Failed to run git: {}", e))?;
-
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).to_string());
-        }
+) -> Result<(), GrovrError> {
+    let op = Operation::start(&app, OperationKind::CreateWorktree, &worktree_path);
+    let result = add_worktree(&op, &repo_path, &worktree_path, &branch_name, &base_branch).await;
+    op.finish(result)
+}
 
-        // When base_branch is a remote branch (e.g., origin/main), git automatically
-        // sets up the new branch to track that remote branch. This causes pushes to
-        // go to the base branch instead of origin/<new-branch>. Remove the upstream
-        // tracking to fix this behavior.
-        let _ = Command::new("git")
-            .args(["branch", "--unset-upstream", &branch_name])
-            .current_dir(&worktree_path)
-            .output();
+async fn add_worktree(
+    op: &Operation,
+    repo_path: &str,
+    worktree_path: &str,
+    branch_name: &str,
+    base_branch: &str,
+) -> Result<(), GrovrError> {
+    op.git(repo_path, &["worktree", "add", "-b", branch_name, worktree_path, base_branch]).await?;
+
+    // When base_branch is a remote branch (e.g., origin/main), git automatically
+    // sets up the new branch to track that remote branch. This causes pushes to
+    // go to the base branch instead of origin/<new-branch>. Remove the upstream
+    // tracking to fix this behavior.
+    let _ = op.git(worktree_path, &["branch", "--unset-upstream", branch_name]).await;
 
-        Ok(())
-    })
-    .await
-    .map_err(|e| format!("Task failed: {}", e))?
+    Ok(())
 }
 
 #[tauri::command]
@@ -120,120 +358,710 @@ pub async fn create_worktree_existing_branch(
     repo_path: String,
     worktree_path: String,
     branch_name: String,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     tokio::task::spawn_blocking(move || {
-        let output = Command::new("git")
-            .args(["worktree", "add", &worktree_path, &branch_name])
-            .current_dir(&repo_path)
-            .output()
-            .map_err(|e| format!("Failed to run git: {}", e))?;
-
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).to_string());
-        }
+        run_git(&repo_path, &["worktree", "add", &worktree_path, &branch_name])?;
 
         Ok(())
     })
-    .await
-    .map_err(|e| format!("Task failed: {}", e))?
+    .await?
+}
+
+/// Worktree checked out from a pull request by `create_worktree_from_pr`.
+#[derive(Debug, Serialize)]
+pub struct PullRequestWorktree {
+    pub path: String,
+    pub branch: String,
+    pub pr_number: u64,
+    pub head_sha: String,
+    pub is_fork: bool,
 }
 
+/// Checks out a GitHub pull request into a new worktree and records the PR in its memo.
+///
+/// The head is fetched from `refs/pull/<n>/head` on origin, which also works for
+/// pull requests from forks. Fork branches are named `<fork-owner>/<branch>` and
+/// track the fork, so pulling picks up new pushes to the PR.
 #[tauri::command]
+pub async fn create_worktree_from_pr(
+    app: tauri::AppHandle,
+    state: State<'_, SettingsState>,
+    repo_path: String,
+    pr_number: u64,
+    worktree_path: String,
+) -> Result<PullRequestWorktree, GrovrError> {
+    let github_host = {
+        let settings = state.0.lock().map_err(|e| e.to_string())?;
+        settings.github_configs.first().and_then(|c| c.host.clone())
+    };
+    let remote = get_github_remote_info(repo_path.clone(), github_host)?
+        .ok_or("The origin remote is not a GitHub repository")?;
+    let pr = integrations::fetch_pull_request(app.clone(), state.clone(), remote.owner, remote.repo, pr_number).await?;
+
+    let branch = pr_branch_name(&pr);
+    let (repo, path, name) = (repo_path.clone(), worktree_path.clone(), branch.clone());
+    let (head_sha, pr) = tokio::task::spawn_blocking(move || {
+        checkout_pull_request(&repo, &path, &name, &pr).map(|sha| (sha, pr))
+    })
+    .await??;
+
+    let path = display_path(Path::new(&worktree_path));
+    let mut settings = state.0.lock().map_err(|e| e.to_string())?;
+    let memo = settings.worktree_memos.entry(path.clone()).or_default();
+    memo.description.get_or_insert(pr.title);
+    memo.pr_number = Some(pr.number);
+    save_settings(&app, &settings)?;
+
+    Ok(PullRequestWorktree { path, branch, pr_number: pr.number, head_sha, is_fork: pr.is_fork })
+}
+
+fn pr_branch_name(pr: &PullRequestHead) -> String {
+    if !pr.is_fork {
+        return pr.head_ref.clone();
+    }
+    match pr.head_repo.as_deref().and_then(|r| r.split('/').next()) {
+        Some(owner) => format!("{}/{}", owner, pr.head_ref),
+        // The fork was deleted, only the PR ref is left
+        None => format!("pr-{}", pr.number),
+    }
+}
+
+/// Fetches the PR head, creates the branch and worktree and sets up tracking.
+/// Returns the checked out commit.
+fn checkout_pull_request(
+    repo_path: &str,
+    worktree_path: &str,
+    branch: &str,
+    pr: &PullRequestHead,
+) -> Result<String, GrovrError> {
+    run_git(repo_path, &["fetch", "origin", &format!("refs/pull/{}/head", pr.number)])?;
+    // Use what was fetched; the head may have moved since the API call
+    let sha = run_git(repo_path, &["rev-parse", "FETCH_HEAD"])?.trim().to_string();
+
+    run_git(repo_path, &["worktree", "add", "-b", branch, worktree_path, &sha])?;
+
+    // Tracking is a convenience, the worktree is usable without it
+    if !pr.is_fork {
+        if run_git(repo_path, &["fetch", "origin", &pr.head_ref]).is_ok() {
+            let upstream = format!("origin/{}", pr.head_ref);
+            let _ = run_git(repo_path, &["branch", "--set-upstream-to", &upstream, branch]);
+        }
+    } else if let Some(url) = &pr.head_clone_url {
+        let _ = run_git(repo_path, &["config", &format!("branch.{}.remote", branch), url]);
+        let merge = format!("refs/heads/{}", pr.head_ref);
+        let _ = run_git(repo_path, &["config", &format!("branch.{}.merge", branch), &merge]);
+    }
+
+    Ok(sha)
+}
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn remove_worktree(
     repo_path: String,
     worktree_path: String,
     force: bool,
     delete_branch: bool,
     branch_name: Option<String>,
-) -> Result<(), String> {
+    allow_locked: bool,
+    safe: bool,
+    acknowledged_plan: Option<String>,
+) -> Result<(), GrovrError> {
     tokio::task::spawn_blocking(move || {
+        let wt = find_worktree(&repo_path, &worktree_path)?;
+
+        // In safe mode, discarding changes or unpushed commits needs the caller
+        // to have seen the current plan
+        if safe
+            && (force || delete_branch)
+            && let Some(wt) = &wt
+        {
+            let plan = removal_plan(&repo_path, wt)?;
+            if plan.loses_work(force, delete_branch) && acknowledged_plan.as_deref() != Some(plan.plan_id.as_str()) {
+                return Err(GrovrError::RemovalNotAcknowledged { path: plan.path, plan_id: plan.plan_id });
+            }
+        }
+
+        let lock_reason = match wt {
+            Some(wt) if wt.is_locked => {
+                if !allow_locked {
+                    return Err(GrovrError::WorktreeLocked { path: wt.path, reason: wt.lock_reason });
+                }
+                Some(wt.lock_reason)
+            }
+            _ => None,
+        };
+
+        // Unlock explicitly rather than passing --force twice, so `force` keeps
+        // meaning "discard uncommitted changes" for locked worktrees too
+        if lock_reason.is_some() {
+            run_git(&repo_path, &["worktree", "unlock", &worktree_path])?;
+        }
+
         let mut args = vec!["worktree", "remove"];
         if force {
             args.push("--force");
         }
         args.push(&worktree_path);
 
-        let output = Command::new("git")
-            .args(&args)
-            .current_dir(&repo_path)
-            .output()
-            .map_err(|e| format!("Failed to run git: {}", e))?;
-
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+        if let Err(e) = run_git(&repo_path, &args) {
+            if let Some(reason) = lock_reason {
+                let _ = lock(&repo_path, &worktree_path, reason.as_deref());
+            }
+            return Err(e);
         }
 
         // Delete the branch after worktree removal if requested
-        if delete_branch {
-            if let Some(branch) = branch_name {
-                // Use -D (force) since the branch may not be fully merged
-                let flag = if force { "-D" } else { "-d" };
-                let output = Command::new("git")
-                    .args(["branch", flag, &branch])
-                    .current_dir(&repo_path)
-                    .output()
-                    .map_err(|e| format!("Worktree removed but failed to delete branch: {}", e))?;
-
-                if !output.status.success() {
-                    let stderr = String::from_utf8_lossy(&output.stderr);
-                    // If soft delete fails, try force delete
-                    if !force && stderr.contains("not fully merged") {
-                        let output = Command::new("git")
-                            .args(["branch", "-D", &branch])
-                            .current_dir(&repo_path)
-                            .output()
-                            .map_err(|e| format!("Worktree removed but failed to force delete branch: {}", e))?;
-
-                        if !output.status.success() {
-                            return Err(format!(
-                                "Worktree removed but failed to delete branch: {}",
-                                String::from_utf8_lossy(&output.stderr)
-                            ));
-                        }
-                    } else {
-                        return Err(format!(
-                            "Worktree removed but failed to delete branch: {}",
-                            stderr
-                        ));
-                    }
+        if delete_branch && let Some(branch) = branch_name {
+            let flag = if force { "-D" } else { "-d" };
+            match run_git(&repo_path, &["branch", flag, &branch]) {
+                // If soft delete fails, fall back to force delete since the
+                // worktree is already gone and the branch would be orphaned
+                Err(GrovrError::BranchNotMerged { .. }) => {
+                    run_git(&repo_path, &["branch", "-D", &branch])?;
+                }
+                result => {
+                    result?;
                 }
             }
         }
 
         Ok(())
     })
-    .await
-    .map_err(|e| format!("Task failed: {}", e))?
+    .await?
 }
 
+/// Dry run of `remove_worktree`: reports what removing the worktree and
+/// deleting its branch would lose, without changing anything.
 #[tauri::command]
-pub fn prune_worktrees(repo_path: String) -> Result<(), String> {
-    let output = Command::new("git")
-        .args(["worktree", "prune"])
-        .current_dir(&repo_path)
-        .output()
-        .map_err(|e| format!("Failed to run git: {}", e))?;
+pub async fn plan_remove_worktree(repo_path: String, worktree_path: String) -> Result<RemovalPlan, GrovrError> {
+    tokio::task::spawn_blocking(move || {
+        let wt = find_worktree(&repo_path, &worktree_path)?
+            .ok_or_else(|| GrovrError::from(format!("Not a worktree of this repository: {}", worktree_path)))?;
+        removal_plan(&repo_path, &wt)
+    })
+    .await?
+}
 
-    if !output.status.success() {
-        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+/// Number of unpushed commits listed in a `RemovalPlan`
+const PLAN_COMMIT_LIMIT: usize = 50;
+
+fn removal_plan(repo_path: &str, wt: &Worktree) -> Result<RemovalPlan, GrovrError> {
+    let branch = (!wt.branch.is_empty()).then(|| wt.branch.clone());
+
+    // A missing directory has nothing left to discard
+    let dirty_files: Vec<String> = if Path::new(&wt.path).exists() {
+        run_git(&wt.path, &["--no-optional-locks", "status", "--porcelain"])?
+            .lines()
+            .filter_map(|line| line.get(3..))
+            .map(str::to_string)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut repo = open_repo(repo_path)?;
+    let mut unpushed_commits = Vec::new();
+    let mut unpushed_count = 0;
+    if let Some(head) = wt.head.as_deref() {
+        let mut walk = repo.revwalk()?;
+        walk.push(git2::Oid::from_str(head)?)?;
+        walk.hide_glob("refs/remotes/*")?;
+        for oid in walk {
+            unpushed_count += 1;
+            if unpushed_commits.len() < PLAN_COMMIT_LIMIT {
+                unpushed_commits.push(commit_info(&repo.find_commit(oid?)?));
+            }
+        }
+    }
+
+    let base_branch = get_default_branch(repo_path.to_string()).ok();
+    let branch_merged = match (&base_branch, wt.head.as_deref()) {
+        (Some(base), Some(head)) => {
+            let head = git2::Oid::from_str(head)?;
+            repo.revparse_single(base)
+                .and_then(|o| o.peel_to_commit())
+                .ok()
+                .map(|base| base.id() == head || repo.graph_descendant_of(base.id(), head).unwrap_or(false))
+        }
+        _ => None,
+    };
+
+    // git stash messages start with "WIP on <branch>:" or "On <branch>:"
+    let mut stashes = Vec::new();
+    if let Some(branch) = &branch {
+        let prefixes = [format!("WIP on {}:", branch), format!("On {}:", branch)];
+        repo.stash_foreach(|index, message, _| {
+            if prefixes.iter().any(|p| message.starts_with(p.as_str())) {
+                stashes.push(StashEntry { index, message: message.to_string() });
+            }
+            true
+        })?;
+    }
+
+    let mut hasher = std::hash::DefaultHasher::new();
+    (&wt.path, &branch, &wt.head, &dirty_files, unpushed_count).hash(&mut hasher);
+    for stash in &stashes {
+        stash.message.hash(&mut hasher);
+    }
+
+    Ok(RemovalPlan {
+        plan_id: format!("{:016x}", hasher.finish()),
+        path: wt.path.clone(),
+        branch,
+        dirty_files,
+        unpushed_commits,
+        unpushed_count,
+        stashes,
+        branch_merged,
+        base_branch,
+    })
+}
+
+/// Prunes stale worktree metadata. Git never prunes locked worktrees, so with
+/// `include_locked` any locked worktree whose directory is gone is unlocked first.
+#[tauri::command]
+pub fn prune_worktrees(repo_path: String, include_locked: bool) -> Result<(), GrovrError> {
+    if include_locked {
+        for wt in get_worktrees(repo_path.clone())? {
+            if wt.is_locked && !Path::new(&wt.path).exists() {
+                run_git(&repo_path, &["worktree", "unlock", &wt.path])?;
+            }
+        }
     }
 
+    run_git(&repo_path, &["worktree", "prune"])?;
     Ok(())
 }
 
 #[tauri::command]
-pub fn get_worktree_status(worktree_path: String) -> Result<WorktreeStatus, String> {
-    let output = Command::new("git")
-        .args(["status", "--porcelain"])
-        .current_dir(&worktree_path)
-        .output()
-        .map_err(|e| format!("Failed to run git: {}", e))?;
+pub fn lock_worktree(
+    repo_path: String,
+    worktree_path: String,
+    reason: Option<String>,
+) -> Result<(), GrovrError> {
+    lock(&repo_path, &worktree_path, reason.as_deref().filter(|r| !r.is_empty()))
+}
 
-    if !output.status.success() {
-        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+#[tauri::command]
+pub fn unlock_worktree(repo_path: String, worktree_path: String) -> Result<(), GrovrError> {
+    run_git(&repo_path, &["worktree", "unlock", &worktree_path])?;
+    Ok(())
+}
+
+fn lock(repo_path: &str, worktree_path: &str, reason: Option<&str>) -> Result<(), GrovrError> {
+    let mut args = vec!["worktree", "lock"];
+    if let Some(reason) = reason {
+        args.extend(["--reason", reason]);
+    }
+    args.push(worktree_path);
+
+    run_git(repo_path, &args)?;
+    Ok(())
+}
+
+/// Looks up a worktree of `repo_path` by path, tolerating symlinks and trailing separators.
+fn find_worktree(repo_path: &str, worktree_path: &str) -> Result<Option<Worktree>, GrovrError> {
+    let target = display_path(Path::new(worktree_path));
+    Ok(get_worktrees(repo_path.to_string())?
+        .into_iter()
+        .find(|wt| display_path(Path::new(&wt.path)) == target))
+}
+
+/// Moves a worktree with `git worktree move` and re-keys its memo to the new path.
+#[tauri::command]
+pub async fn move_worktree(
+    app: tauri::AppHandle,
+    state: State<'_, SettingsState>,
+    repo_path: String,
+    from: String,
+    to: String,
+) -> Result<String, GrovrError> {
+    let (old_key, new_key) =
+        tokio::task::spawn_blocking(move || move_worktree_dir(&repo_path, &from, &to)).await??;
+
+    let mut settings = state.0.lock().map_err(|e| e.to_string())?;
+    rekey_memo(&mut settings.worktree_memos, &old_key, &new_key);
+    save_settings(&app, &settings)?;
+
+    Ok(new_key)
+}
+
+/// Moves every linked worktree of a project to the path its current worktree
+/// template produces for it. With `dry_run` only the plan is returned.
+#[tauri::command]
+pub async fn relocate_worktrees(
+    app: tauri::AppHandle,
+    state: State<'_, SettingsState>,
+    repo_path: String,
+    dry_run: bool,
+) -> Result<Vec<WorktreeRelocation>, GrovrError> {
+    let (template, memos) = {
+        let settings = state.0.lock().map_err(|e| e.to_string())?;
+        let template = settings.project_settings(&repo_path).worktree_template;
+        (template, settings.worktree_memos.clone())
+    };
+
+    let results = tokio::task::spawn_blocking(move || -> Result<_, GrovrError> {
+        let mut results = Vec::new();
+
+        for wt in get_worktrees(repo_path.clone())? {
+            // Only branch worktrees that are present and movable have a template path
+            if wt.is_main || wt.is_detached || wt.is_locked || wt.is_prunable {
+                continue;
+            }
+
+            let memo = memos.get(&wt.path).cloned().unwrap_or_default();
+            let target = expand_template(
+                &template,
+                &TemplateVars {
+                    repo_path: &repo_path,
+                    branch: &wt.branch,
+                    description: memo.description.as_deref().unwrap_or_default(),
+                    issue: memo.issue_number.as_deref().unwrap_or_default(),
+                },
+            )?;
+            if display_path(Path::new(&target)) == wt.path {
+                continue;
+            }
+
+            let mut result = WorktreeRelocation {
+                from: wt.path.clone(),
+                to: target,
+                moved: false,
+                error: None,
+            };
+            if !dry_run {
+                match move_worktree_dir(&repo_path, &wt.path, &result.to) {
+                    Ok((_, new_key)) => {
+                        result.to = new_key;
+                        result.moved = true;
+                    }
+                    Err(e) => result.error = Some(e.to_string()),
+                }
+            }
+            results.push(result);
+        }
+
+        Ok(results)
+    })
+    .await??;
+
+    if results.iter().any(|r| r.moved) {
+        let mut settings = state.0.lock().map_err(|e| e.to_string())?;
+        for result in results.iter().filter(|r| r.moved) {
+            rekey_memo(&mut settings.worktree_memos, &result.from, &result.to);
+        }
+        save_settings(&app, &settings)?;
+    }
+
+    Ok(results)
+}
+
+/// Runs `git worktree move` and returns the worktree's old and new memo keys.
+fn move_worktree_dir(repo_path: &str, from: &str, to: &str) -> Result<(String, String), GrovrError> {
+    let wt = find_worktree(repo_path, from)?
+        .ok_or_else(|| GrovrError::from(format!("Not a worktree of this repository: {}", from)))?;
+    if wt.is_main {
+        return Err("The main worktree cannot be moved".into());
+    }
+    if wt.is_locked {
+        return Err(GrovrError::WorktreeLocked { path: wt.path, reason: wt.lock_reason });
+    }
+    if Path::new(to).exists() {
+        return Err(GrovrError::PathExists { path: to.to_string() });
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
+    // git renames into place and does not create missing parent directories
+    if let Some(parent) = Path::new(to).parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    run_git(repo_path, &["worktree", "move", &wt.path, to])?;
+
+    Ok((wt.path, display_path(Path::new(to))))
+}
+
+fn rekey_memo(memos: &mut HashMap<String, WorktreeMemo>, from: &str, to: &str) {
+    if let Some(memo) = memos.remove(from) {
+        memos.insert(to.to_string(), memo);
+    }
+}
+
+/// Characters that are awkward or invalid in paths and shells
+const UNSAFE_PATH_CHARS: &str = "/:*?\"<>|\\&;'`$#%!()[]{}^~@+=,";
+
+/// Values substituted into a worktree path template.
+pub(crate) struct TemplateVars<'a> {
+    pub repo_path: &'a str,
+    pub branch: &'a str,
+    pub description: &'a str,
+    pub issue: &'a str,
+}
+
+/// Expands a worktree path template.
+///
+/// Supported variables are `{project}` (the repo path), `{repo_parent}`,
+/// `{branch}`, `{description}`, `{issue}`, `{user}` and `{date}` (UTC,
+/// `YYYY-MM-DD`). `{slug:<name>}` lowercases any of them into `a-z0-9-`.
+/// Components left with dangling `-` by empty values are tidied up and a
+/// relative result is placed next to the repository.
+pub(crate) fn expand_template(template: &str, vars: &TemplateVars) -> Result<String, GrovrError> {
+    let repo_path = vars.repo_path.trim_end_matches(['/', '\\']);
+    let repo_parent = Path::new(repo_path)
+        .parent()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let value = |name: &str| -> Result<String, GrovrError> {
+        Ok(match name {
+            "project" => repo_path.to_string(),
+            "repo_parent" => repo_parent.clone(),
+            // Branch slashes would otherwise nest directories
+            "branch" => sanitize_segment(&vars.branch.replace(['/', '\\'], "-")),
+            "description" => sanitize_segment(vars.description),
+            "issue" => sanitize_segment(vars.issue),
+            "user" => sanitize_segment(&current_user()),
+            "date" => utc_date(),
+            _ => return Err(format!("Unknown template variable {{{}}}", name).into()),
+        })
+    };
+
+    let mut expanded = String::new();
+    let mut rest = template.trim();
+    while let Some(open) = rest.find('{') {
+        let Some(close) = rest[open..].find('}').map(|i| open + i) else { break };
+        expanded.push_str(&rest[..open]);
+        let name = &rest[open + 1..close];
+        match name.strip_prefix("slug:") {
+            Some(inner) => expanded.push_str(&slugify(&value(inner)?)),
+            None => expanded.push_str(&value(name)?),
+        }
+        rest = &rest[close + 1..];
+    }
+    expanded.push_str(rest);
+
+    let expanded = match expanded.strip_prefix("~/") {
+        Some(tail) => match std::env::var("HOME").or_else(|_| std::env::var("USERPROFILE")) {
+            Ok(home) => format!("{}/{}", home.trim_end_matches('/'), tail),
+            Err(_) => expanded,
+        },
+        None => expanded,
+    };
+
+    // "{branch}-{description}" with no description must not leave "feat-"
+    let tidy: Vec<&str> = expanded
+        .split('/')
+        .enumerate()
+        .map(|(i, c)| if i == 0 { c } else { c.trim_matches('-') })
+        .filter(|c| !c.is_empty())
+        .collect();
+    let mut path = tidy.join("/");
+    if expanded.starts_with('/') {
+        path.insert(0, '/');
+    }
+    if path.is_empty() {
+        return Err("Worktree path template produced an empty path".into());
+    }
+
+    if Path::new(&path).is_absolute() {
+        Ok(path)
+    } else {
+        Ok(Path::new(&repo_parent).join(path).to_string_lossy().to_string())
+    }
+}
+
+fn sanitize_segment(value: &str) -> String {
+    value
+        .trim()
+        .chars()
+        .map(|c| if UNSAFE_PATH_CHARS.contains(c) { '_' } else { c })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn slugify(value: &str) -> String {
+    value
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn current_user() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_else(|_| "user".to_string())
+}
+
+/// Today's UTC date as `YYYY-MM-DD`
+fn utc_date() -> String {
+    let secs = std::time::SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+    let (y, m, d) = civil_from_days(secs.div_euclid(86_400));
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
+
+/// Days since 1970-01-01 to a proleptic Gregorian (year, month, day)
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PathCollision {
+    /// An existing worktree is registered at the path
+    Worktree { branch: String },
+    /// A file or directory that is not a worktree is in the way
+    Directory,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ResolvedWorktreePath {
+    pub path: String,
+    pub collision: Option<PathCollision>,
+    /// `path`, or the first `path-N` that collides with nothing
+    pub available_path: String,
+}
+
+/// Computes where a new worktree goes according to the project's worktree template.
+#[tauri::command]
+pub fn resolve_worktree_path(
+    state: State<SettingsState>,
+    repo_path: String,
+    branch: String,
+    description: Option<String>,
+    issue: Option<String>,
+) -> Result<ResolvedWorktreePath, GrovrError> {
+    let template = {
+        let settings = state.0.lock().map_err(|e| e.to_string())?;
+        settings.project_settings(&repo_path).worktree_template
+    };
+
+    let vars = TemplateVars {
+        repo_path: &repo_path,
+        branch: &branch,
+        description: description.as_deref().unwrap_or_default(),
+        issue: issue.as_deref().unwrap_or_default(),
+    };
+    resolve_path(&template, &vars)
+}
+
+fn resolve_path(template: &str, vars: &TemplateVars) -> Result<ResolvedWorktreePath, GrovrError> {
+    let path = expand_template(template, vars)?;
+    let worktrees = get_worktrees(vars.repo_path.to_string())?;
+
+    let collision_at = |candidate: &str| {
+        let normalized = display_path(Path::new(candidate));
+        if let Some(wt) = worktrees
+            .iter()
+            .find(|wt| wt.path.trim_end_matches('/') == candidate.trim_end_matches('/') || wt.path == normalized)
+        {
+            return Some(PathCollision::Worktree { branch: wt.branch.clone() });
+        }
+        Path::new(candidate).exists().then_some(PathCollision::Directory)
+    };
+
+    let collision = collision_at(&path);
+    let mut available_path = path.clone();
+    let mut n = 2;
+    while collision_at(&available_path).is_some() {
+        available_path = format!("{}-{}", path, n);
+        n += 1;
+    }
+
+    Ok(ResolvedWorktreePath { path, collision, available_path })
+}
+
+#[tauri::command]
+pub async fn get_worktree_status(worktree_path: String) -> Result<WorktreeStatus, GrovrError> {
+    tokio::task::spawn_blocking(move || {
+        let base_branch = get_default_branch(worktree_path.clone()).ok();
+        worktree_status(&worktree_path, base_branch.as_deref())
+    })
+    .await?
+}
+
+/// Max number of worktree statuses computed at once by `get_project_status`
+const STATUS_CONCURRENCY: usize = 8;
+/// Per-worktree budget before `get_project_status` reports a timeout for it
+const STATUS_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Computes the status of every worktree of a project in one call.
+///
+/// Worktrees are processed concurrently on the blocking pool, bounded by
+/// `STATUS_CONCURRENCY`. A slow or broken worktree only fails its own entry.
+#[tauri::command]
+pub async fn get_project_status(
+    repo_path: String,
+) -> Result<HashMap<String, WorktreeStatusResult>, GrovrError> {
+    let (worktrees, base_branch) = tokio::task::spawn_blocking(move || {
+        let base_branch = get_default_branch(repo_path.clone()).ok();
+        get_worktrees(repo_path).map(|w| (w, base_branch))
+    })
+    .await??;
+
+    let semaphore = Arc::new(Semaphore::new(STATUS_CONCURRENCY));
+    let mut results = HashMap::new();
+    let mut tasks = JoinSet::new();
+
+    for wt in worktrees {
+        if wt.is_bare {
+            continue;
+        }
+        if wt.is_prunable {
+            let reason = wt.prunable_reason.unwrap_or_else(|| "Worktree directory is missing".to_string());
+            results.insert(wt.path, WorktreeStatusResult { status: None, error: Some(reason.into()) });
+            continue;
+        }
+
+        let semaphore = semaphore.clone();
+        let base_branch = base_branch.clone();
+        tasks.spawn(async move {
+            // The permit moves into the blocking job so a timed-out git process
+            // still counts against the limit until it actually exits
+            let permit = semaphore.acquire_owned().await;
+            let path = wt.path.clone();
+            let job = tokio::task::spawn_blocking(move || {
+                let _permit = permit;
+                worktree_status(&path, base_branch.as_deref())
+            });
+
+            let result = match tokio::time::timeout(STATUS_TIMEOUT, job).await {
+                Ok(joined) => joined.map_err(GrovrError::from).and_then(|r| r),
+                Err(_) => Err(GrovrError::Timeout { seconds: STATUS_TIMEOUT.as_secs() }),
+            };
+            (wt.path, result)
+        });
+    }
+
+    while let Some(joined) = tasks.join_next().await {
+        let (path, result) = joined?;
+        let entry = match result {
+            Ok(status) => WorktreeStatusResult { status: Some(status), error: None },
+            Err(e) => WorktreeStatusResult { status: None, error: Some(e) },
+        };
+        results.insert(path, entry);
+    }
+
+    Ok(results)
+}
+
+pub(crate) fn worktree_status(worktree_path: &str, base_branch: Option<&str>) -> Result<WorktreeStatus, GrovrError> {
+    let stdout = run_git(worktree_path, &["--no-optional-locks", "status", "--porcelain"])?;
     let mut staged = 0;
     let mut unstaged = 0;
     let mut untracked = 0;
@@ -257,26 +1085,446 @@ pub fn get_worktree_status(worktree_path: String) -> Result<WorktreeStatus, Stri
         }
     }
 
-    Ok(WorktreeStatus {
+    let mut status = WorktreeStatus {
         has_changes: staged > 0 || unstaged > 0 || untracked > 0,
         staged,
         unstaged,
         untracked,
+        upstream: None,
+        upstream_gone: false,
+        ahead: None,
+        behind: None,
+        base_branch: base_branch.map(|b| b.to_string()),
+        ahead_of_base: None,
+        behind_base: None,
+        last_commit: None,
+    };
+
+    // Tracking info is best effort: an unborn branch or missing base is not an error
+    let repo = open_repo(worktree_path)?;
+    fill_tracking(&repo, base_branch, &mut status);
+
+    Ok(status)
+}
+
+fn fill_tracking(repo: &Repository, base_branch: Option<&str>, status: &mut WorktreeStatus) {
+    let Ok(head) = repo.head() else { return };
+    let Some(head_oid) = head.target() else { return };
+
+    if let Ok(commit) = repo.find_commit(head_oid) {
+        status.last_commit = Some(commit_info(&commit));
+    }
+
+    if head.is_branch()
+        && let Some(refname) = head.name()
+        && let Ok(upstream) = repo.branch_upstream_name(refname)
+        && let Some(upstream) = upstream.as_str()
+    {
+        status.upstream = Some(short_ref_name(upstream).to_string());
+        match repo.refname_to_id(upstream) {
+            Ok(upstream_oid) => {
+                if let Ok((ahead, behind)) = repo.graph_ahead_behind(head_oid, upstream_oid) {
+                    status.ahead = Some(ahead);
+                    status.behind = Some(behind);
+                }
+            }
+            // Configured upstream without a ref: deleted on the remote and pruned
+            Err(_) => status.upstream_gone = true,
+        }
+    }
+
+    if let Some(base) = base_branch
+        && let Ok(base_commit) = repo.revparse_single(base).and_then(|o| o.peel_to_commit())
+        && let Ok((ahead, behind)) = repo.graph_ahead_behind(head_oid, base_commit.id())
+    {
+        status.ahead_of_base = Some(ahead);
+        status.behind_base = Some(behind);
+    }
+}
+
+fn commit_info(commit: &git2::Commit) -> CommitInfo {
+    CommitInfo {
+        sha: commit.id().to_string(),
+        summary: commit.summary().unwrap_or_default().to_string(),
+        author: commit.author().name().unwrap_or_default().to_string(),
+        time: commit.time().seconds(),
+    }
+}
+
+/// `refs/remotes/origin/main` -> `origin/main`, `refs/heads/main` -> `main`
+fn short_ref_name(refname: &str) -> &str {
+    refname
+        .strip_prefix("refs/remotes/")
+        .or_else(|| refname.strip_prefix("refs/heads/"))
+        .unwrap_or(refname)
+}
+
+// ============ Stale Worktrees ============
+
+/// Classifies the linked worktrees of a project against `criteria`.
+///
+/// Only worktrees matching at least one criterion are returned. Integration
+/// lookups that fail are skipped rather than failing the whole scan.
+#[tauri::command]
+pub async fn find_stale_worktrees(
+    app: tauri::AppHandle,
+    state: State<'_, SettingsState>,
+    repo_path: String,
+    criteria: StaleCriteria,
+) -> Result<Vec<StaleWorktree>, GrovrError> {
+    let (github_host, memos) = {
+        let settings = state.0.lock().map_err(|e| e.to_string())?;
+        let host = settings.github_configs.first().and_then(|c| c.host.clone());
+        (host, settings.worktree_memos.clone())
+    };
+
+    let repo = repo_path.clone();
+    let worktrees = tokio::task::spawn_blocking(move || get_worktrees(repo)).await??;
+    let mut statuses = get_project_status(repo_path.clone()).await?;
+
+    let remote = if criteria.pr_closed {
+        get_github_remote_info(repo_path.clone(), github_host).ok().flatten()
+    } else {
+        None
+    };
+    let now = std::time::SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+
+    let mut stale = Vec::new();
+    for wt in worktrees {
+        if wt.is_main || wt.is_bare || wt.is_prunable || wt.branch.is_empty() {
+            continue;
+        }
+        let status = statuses.remove(&wt.path).and_then(|r| r.status);
+        let mut reasons = Vec::new();
+
+        if let Some(status) = &status {
+            if criteria.merged
+                && status.ahead_of_base == Some(0)
+                && let Some(base) = &status.base_branch
+            {
+                reasons.push(StaleReason::Merged { base_branch: base.clone() });
+            }
+            if criteria.upstream_gone
+                && status.upstream_gone
+                && let Some(upstream) = &status.upstream
+            {
+                reasons.push(StaleReason::UpstreamGone { upstream: upstream.clone() });
+            }
+            if let Some(max_days) = criteria.inactive_days
+                && let Some(commit) = &status.last_commit
+            {
+                let days = (now - commit.time).max(0) as u64 / 86_400;
+                if days >= max_days {
+                    reasons.push(StaleReason::Inactive { days });
+                }
+            }
+        }
+
+        if let Some(remote) = &remote
+            && let Ok(prs) = integrations::fetch_pull_requests(
+                app.clone(),
+                state.clone(),
+                remote.owner.clone(),
+                remote.repo.clone(),
+                wt.branch.clone(),
+            )
+            .await
+            && let Some(pr) = prs.first()
+            && pr.state == "closed"
+        {
+            reasons.push(StaleReason::PrClosed { number: pr.number, merged: pr.merged, url: pr.url.clone() });
+        }
+
+        if criteria.jira_done
+            && let Some(issue_key) = memos.get(&wt.path).and_then(|m| m.issue_number.clone())
+            && let Ok(Some(issue)) =
+                integrations::fetch_jira_issue(app.clone(), state.clone(), issue_key).await
+            && issue.status_category == "done"
+        {
+            reasons.push(StaleReason::JiraDone { key: issue.key, status: issue.status });
+        }
+
+        if !reasons.is_empty() {
+            stale.push(StaleWorktree { worktree: wt, reasons, status });
+        }
+    }
+
+    Ok(stale)
+}
+
+/// Removes the given worktrees one by one with `remove_worktree` semantics.
+///
+/// Each entry reports its own outcome; locked worktrees are left alone.
+#[tauri::command]
+pub async fn cleanup_worktrees(
+    repo_path: String,
+    worktree_paths: Vec<String>,
+    force: bool,
+    delete_branch: bool,
+) -> Result<Vec<WorktreeCleanup>, GrovrError> {
+    let mut results = Vec::new();
+
+    for path in worktree_paths {
+        let repo = repo_path.clone();
+        let lookup = path.clone();
+        let wt = tokio::task::spawn_blocking(move || find_worktree(&repo, &lookup)).await??;
+
+        let (branch, result) = match wt {
+            None => (None, Err(GrovrError::from(format!("Not a worktree of this repository: {}", path)))),
+            Some(wt) if wt.is_main => (None, Err("The main worktree cannot be removed".into())),
+            Some(wt) => {
+                let branch = (!wt.branch.is_empty()).then(|| wt.branch.clone());
+                let result = remove_worktree(
+                    repo_path.clone(),
+                    wt.path,
+                    force,
+                    delete_branch && branch.is_some(),
+                    branch.clone(),
+                    false,
+                    false,
+                    None,
+                )
+                .await;
+                (branch, result)
+            }
+        };
+
+        results.push(WorktreeCleanup {
+            path,
+            branch,
+            removed: result.is_ok(),
+            error: result.err(),
+        });
+    }
+
+    Ok(results)
+}
+
+// ============ Archive ============
+
+const ARCHIVE_REF_PREFIX: &str = "refs/grovr/archive/";
+
+/// Removes a worktree and its branch, keeping everything restorable.
+///
+/// The branch tip, plus a commit of any uncommitted changes, is kept alive
+/// under `refs/grovr/archive/` and the worktree's memo moves into the record.
+#[tauri::command]
+pub async fn archive_worktree(
+    app: tauri::AppHandle,
+    state: State<'_, SettingsState>,
+    repo_path: String,
+    worktree_path: String,
+) -> Result<ArchivedWorktree, GrovrError> {
+    let base_branch = {
+        let settings = state.0.lock().map_err(|e| e.to_string())?;
+        settings
+            .projects
+            .iter()
+            .find(|p| p.repo_path == repo_path)
+            .and_then(|p| p.default_base_branch.clone())
+    };
+
+    let mut archived =
+        tokio::task::spawn_blocking(move || archive_worktree_dir(&repo_path, &worktree_path, base_branch)).await??;
+
+    let mut settings = state.0.lock().map_err(|e| e.to_string())?;
+    archived.memo = settings.worktree_memos.remove(&archived.path);
+    settings.archived_worktrees.push(archived.clone());
+    save_settings(&app, &settings)?;
+
+    Ok(archived)
+}
+
+#[tauri::command]
+pub fn get_archived_worktrees(
+    state: State<SettingsState>,
+    repo_path: String,
+) -> Result<Vec<ArchivedWorktree>, GrovrError> {
+    let settings = state.0.lock().map_err(|e| e.to_string())?;
+    Ok(settings
+        .archived_worktrees
+        .iter()
+        .filter(|a| a.repo_path == repo_path)
+        .cloned()
+        .collect())
+}
+
+/// Recreates an archived worktree and its branch at the original path.
+#[tauri::command]
+pub async fn restore_archived_worktree(
+    app: tauri::AppHandle,
+    state: State<'_, SettingsState>,
+    repo_path: String,
+    archive_ref: String,
+) -> Result<String, GrovrError> {
+    let archived = find_archived(&state, &repo_path, &archive_ref)?;
+
+    let record = archived.clone();
+    tokio::task::spawn_blocking(move || restore_worktree_dir(&record)).await??;
+
+    let mut settings = state.0.lock().map_err(|e| e.to_string())?;
+    settings.archived_worktrees.retain(|a| !(a.repo_path == repo_path && a.archive_ref == archive_ref));
+    if let Some(memo) = archived.memo {
+        settings.worktree_memos.insert(archived.path.clone(), memo);
+    }
+    save_settings(&app, &settings)?;
+
+    Ok(archived.path)
+}
+
+/// Permanently drops an archive and the commits only it was keeping alive.
+#[tauri::command]
+pub fn delete_archived_worktree(
+    app: tauri::AppHandle,
+    state: State<SettingsState>,
+    repo_path: String,
+    archive_ref: String,
+) -> Result<(), GrovrError> {
+    find_archived(&state, &repo_path, &archive_ref)?;
+    run_git(&repo_path, &["update-ref", "-d", &archive_ref])?;
+
+    let mut settings = state.0.lock().map_err(|e| e.to_string())?;
+    settings.archived_worktrees.retain(|a| !(a.repo_path == repo_path && a.archive_ref == archive_ref));
+    save_settings(&app, &settings)
+}
+
+fn find_archived(state: &SettingsState, repo_path: &str, archive_ref: &str) -> Result<ArchivedWorktree, GrovrError> {
+    let settings = state.0.lock().map_err(|e| e.to_string())?;
+    settings
+        .archived_worktrees
+        .iter()
+        .find(|a| a.repo_path == repo_path && a.archive_ref == archive_ref)
+        .cloned()
+        .ok_or_else(|| format!("No archived worktree for {}", archive_ref).into())
+}
+
+fn archive_worktree_dir(
+    repo_path: &str,
+    worktree_path: &str,
+    base_branch: Option<String>,
+) -> Result<ArchivedWorktree, GrovrError> {
+    let wt = find_worktree(repo_path, worktree_path)?
+        .ok_or_else(|| GrovrError::from(format!("Not a worktree of this repository: {}", worktree_path)))?;
+    if wt.is_main {
+        return Err("The main worktree cannot be archived".into());
+    }
+    if wt.is_locked {
+        return Err(GrovrError::WorktreeLocked { path: wt.path, reason: wt.lock_reason });
+    }
+    let head = wt.head.clone().ok_or("Cannot archive a worktree without commits")?;
+    let branch = (!wt.branch.is_empty()).then(|| wt.branch.clone());
+
+    let repo = open_repo(repo_path)?;
+    let upstream = branch.as_ref().and_then(|b| {
+        let upstream = repo.branch_upstream_name(&format!("refs/heads/{}", b)).ok()?;
+        upstream.as_str().map(|u| short_ref_name(u).to_string())
+    });
+
+    let exists = Path::new(&wt.path).exists();
+    let snapshot = if exists { snapshot_changes(&wt, &head)? } else { None };
+
+    // Archiving the same branch twice keeps both archives
+    let name = branch.clone().unwrap_or_else(|| format!("detached-{}", &head[..7.min(head.len())]));
+    let mut archive_ref = format!("{}{}", ARCHIVE_REF_PREFIX, name);
+    let mut n = 2;
+    while repo.find_reference(&archive_ref).is_ok() {
+        archive_ref = format!("{}{}-{}", ARCHIVE_REF_PREFIX, name, n);
+        n += 1;
+    }
+    let target = snapshot.as_deref().unwrap_or(&head);
+    run_git(repo_path, &["update-ref", &archive_ref, target])?;
+
+    // Everything is saved in the archive ref, so removal can be forced
+    if exists {
+        run_git(repo_path, &["worktree", "remove", "--force", &wt.path])?;
+    } else {
+        run_git(repo_path, &["worktree", "prune"])?;
+    }
+    if let Some(branch) = &branch {
+        run_git(repo_path, &["branch", "-D", branch])?;
+    }
+
+    Ok(ArchivedWorktree {
+        repo_path: repo_path.to_string(),
+        path: wt.path,
+        branch,
+        archive_ref,
+        head,
+        has_changes: snapshot.is_some(),
+        upstream,
+        base_branch,
+        memo: None,
+        archived_at: std::time::SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or_default(),
     })
 }
 
+/// Commits staged, unstaged and untracked changes on top of `head` without
+/// touching the worktree's index. Returns `None` for a clean worktree.
+fn snapshot_changes(wt: &Worktree, head: &str) -> Result<Option<String>, GrovrError> {
+    let status = run_git(&wt.path, &["--no-optional-locks", "status", "--porcelain"])?;
+    if status.trim().is_empty() {
+        return Ok(None);
+    }
+
+    let index = Path::new(&wt.gitdir).join("grovr-archive-index");
+    let index = index.to_string_lossy();
+    let env = [("GIT_INDEX_FILE", index.as_ref())];
+    let result = (|| {
+        run_git_env(&wt.path, &["read-tree", head], &env)?;
+        run_git_env(&wt.path, &["add", "-A"], &env)?;
+        let tree = run_git_env(&wt.path, &["write-tree"], &env)?;
+        let message = format!("Archived changes of {}", wt.path);
+        run_git(&wt.path, &["commit-tree", tree.trim(), "-p", head, "-m", &message])
+    })();
+    let _ = std::fs::remove_file(index.as_ref());
+
+    Ok(Some(result?.trim().to_string()))
+}
+
+fn restore_worktree_dir(archived: &ArchivedWorktree) -> Result<(), GrovrError> {
+    let repo_path = archived.repo_path.as_str();
+    if Path::new(&archived.path).exists() {
+        return Err(GrovrError::PathExists { path: archived.path.clone() });
+    }
+
+    match &archived.branch {
+        Some(branch) => run_git(repo_path, &["worktree", "add", "-b", branch, &archived.path, &archived.head])?,
+        None => run_git(repo_path, &["worktree", "add", "--detach", &archived.path, &archived.head])?,
+    };
+
+    // Bring the saved changes back as unstaged edits
+    if archived.has_changes {
+        let source = format!("--source={}", archived.archive_ref);
+        run_git(&archived.path, &["restore", &source, "--worktree", "--", "."])?;
+    }
+
+    if let (Some(branch), Some(upstream)) = (&archived.branch, &archived.upstream) {
+        // The upstream may have been deleted since; the branch is still usable
+        let _ = run_git(&archived.path, &["branch", "--set-upstream-to", upstream, branch]);
+    }
+
+    run_git(repo_path, &["update-ref", "-d", &archived.archive_ref])?;
+    Ok(())
+}
+
 // ============ Branch Commands ============
 
 #[tauri::command]
-pub fn get_branches(repo_path: String, include_remote: bool) -> Result<Vec<Branch>, String> {
-    let repo = Repository::open(&repo_path).map_err(|e| e.to_string())?;
+pub fn get_branches(repo_path: String, include_remote: bool) -> Result<Vec<Branch>, GrovrError> {
+    let repo = open_repo(&repo_path)?;
     let mut branches_vec = Vec::new();
 
     // Get local branches
-    let local_branches = repo.branches(Some(BranchType::Local)).map_err(|e| e.to_string())?;
+    let local_branches = repo.branches(Some(BranchType::Local))?;
     for branch_result in local_branches {
-        let (branch, _) = branch_result.map_err(|e| e.to_string())?;
-        let name = branch.name().map_err(|e| e.to_string())?;
+        let (branch, _) = branch_result?;
+        let name = branch.name()?;
         if let Some(name) = name {
             branches_vec.push(Branch {
                 name: name.to_string(),
@@ -288,10 +1536,10 @@ pub fn get_branches(repo_path: String, include_remote: bool) -> Result<Vec<Branc
 
     // Get remote branches if requested
     if include_remote {
-        let remote_branches = repo.branches(Some(BranchType::Remote)).map_err(|e| e.to_string())?;
+        let remote_branches = repo.branches(Some(BranchType::Remote))?;
         for branch_result in remote_branches {
-            let (branch, _) = branch_result.map_err(|e| e.to_string())?;
-            let name = branch.name().map_err(|e| e.to_string())?;
+            let (branch, _) = branch_result?;
+            let name = branch.name()?;
             if let Some(name) = name {
                 branches_vec.push(Branch {
                     name: name.to_string(),
@@ -306,128 +1554,159 @@ pub fn get_branches(repo_path: String, include_remote: bool) -> Result<Vec<Branc
 }
 
 #[tauri::command]
-pub fn get_current_branch(repo_path: String) -> Result<String, String> {
-    let repo = Repository::open(&repo_path).map_err(|e| e.to_string())?;
-    let head = repo.head().map_err(|e| e.to_string())?;
+pub fn get_current_branch(repo_path: String) -> Result<String, GrovrError> {
+    let repo = open_repo(&repo_path)?;
+    let head = repo.head()?;
 
     if head.is_branch() {
         head.shorthand()
             .map(|s| s.to_string())
-            .ok_or_else(|| "Could not get branch name".to_string())
+            .ok_or_else(|| "Could not get branch name".into())
     } else {
-        Err("HEAD is not a branch".to_string())
+        Err("HEAD is not a branch".into())
     }
 }
 
 #[tauri::command]
-pub fn get_default_branch(repo_path: String) -> Result<String, String> {
+pub fn get_default_branch(repo_path: String) -> Result<String, GrovrError> {
     // Try to find origin/HEAD or origin/main or origin/master
-    let output = Command::new("git")
-        .args(["symbolic-ref", "refs/remotes/origin/HEAD", "--short"])
-        .current_dir(&repo_path)
-        .output()
-        .map_err(|e| format!("Failed to run git: {}", e))?;
-
-    if output.status.success() {
-        let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        return Ok(branch);
+    if let Ok(stdout) = run_git(&repo_path, &["symbolic-ref", "refs/remotes/origin/HEAD", "--short"]) {
+        return Ok(stdout.trim().to_string());
     }
 
     // Fallback: check if origin/main exists
-    let output = Command::new("git")
-        .args(["rev-parse", "--verify", "origin/main"])
-        .current_dir(&repo_path)
-        .output()
-        .map_err(|e| format!("Failed to run git: {}", e))?;
-
-    if output.status.success() {
+    if run_git(&repo_path, &["rev-parse", "--verify", "origin/main"]).is_ok() {
         return Ok("origin/main".to_string());
     }
 
     // Fallback: check if origin/master exists
-    let output = Command::new("git")
-        .args(["rev-parse", "--verify", "origin/master"])
-        .current_dir(&repo_path)
-        .output()
-        .map_err(|e| format!("Failed to run git: {}", e))?;
-
-    if output.status.success() {
+    if run_git(&repo_path, &["rev-parse", "--verify", "origin/master"]).is_ok() {
         return Ok("origin/master".to_string());
     }
 
-    Err("Could not determine default branch".to_string())
+    // Surface a missing repo or git binary instead of a generic failure
+    open_repo(&repo_path)?;
+    Err("Could not determine default branch".into())
 }
 
 #[tauri::command]
-pub fn delete_branch(repo_path: String, branch_name: String, force: bool) -> Result<(), String> {
+pub fn delete_branch(repo_path: String, branch_name: String, force: bool) -> Result<(), GrovrError> {
     let flag = if force { "-D" } else { "-d" };
-
-    let output = Command::new("git")
-        .args(["branch", flag, &branch_name])
-        .current_dir(&repo_path)
-        .output()
-        .map_err(|e| format!("Failed to run git: {}", e))?;
-
-    if !output.status.success() {
-        return Err(String::from_utf8_lossy(&output.stderr).to_string());
-    }
-
+    run_git(&repo_path, &["branch", flag, &branch_name])?;
     Ok(())
 }
 
 #[tauri::command]
-pub fn rename_branch(repo_path: String, old_name: String, new_name: String) -> Result<(), String> {
-    let output = Command::new("git")
-        .args(["branch", "-m", &old_name, &new_name])
-        .current_dir(&repo_path)
-        .output()
-        .map_err(|e| format!("Failed to run git: {}", e))?;
-
-    if !output.status.success() {
-        return Err(String::from_utf8_lossy(&output.stderr).to_string());
-    }
-
+pub fn rename_branch(repo_path: String, old_name: String, new_name: String) -> Result<(), GrovrError> {
+    run_git(&repo_path, &["branch", "-m", &old_name, &new_name])?;
     Ok(())
 }
 
 // ============ Git Operations ============
 
-#[tauri::command]
-pub async fn git_fetch(repo_path: String) -> Result<(), String> {
-    tokio::task::spawn_blocking(move || {
-        let output = Command::new("git")
-            .args(["fetch", "--all", "--prune"])
-            .current_dir(&repo_path)
-            .output()
-            .map_err(|e| format!("Failed to run git: {}", e))?;
+/// Narrows `git_fetch` to what is needed. The default fetches every remote.
+#[derive(Debug, Deserialize, Default)]
+pub struct FetchOptions {
+    /// Fetch only this remote
+    pub remote: Option<String>,
+    /// Branches or refspecs to fetch from `remote`
+    #[serde(default)]
+    pub refspecs: Vec<String>,
+    /// Shallow fetch of this many commits. Makes a full clone shallow too.
+    pub depth: Option<u32>,
+    /// Partial clone filter, e.g. `blob:none`. Git refuses this unless the
+    /// remote was cloned as a partial clone.
+    pub filter: Option<String>,
+    #[serde(default)]
+    pub prune: bool,
+}
 
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).to_string());
+fn fetch_args(options: &FetchOptions) -> Result<Vec<String>, GrovrError> {
+    let mut args = vec!["fetch".to_string(), "--progress".to_string()];
+    if options.prune {
+        args.push("--prune".to_string());
+    }
+    if let Some(depth) = options.depth {
+        args.push(format!("--depth={}", depth));
+    }
+    if let Some(filter) = options.filter.as_deref().filter(|f| !f.is_empty()) {
+        args.push(format!("--filter={}", filter));
+    }
+    match options.remote.as_deref().filter(|r| !r.is_empty()) {
+        Some(remote) => {
+            args.push(remote.to_string());
+            args.extend(options.refspecs.iter().cloned());
         }
+        None if options.refspecs.is_empty() => args.push("--all".to_string()),
+        None => return Err("Refspecs need a remote to fetch from".into()),
+    }
+    Ok(args)
+}
 
-        Ok(())
-    })
-    .await
-    .map_err(|e| format!("Task failed: {}", e))?
+/// Fetches as a tracked operation with progress events. Without options this
+/// fetches and prunes all remotes.
+#[tauri::command]
+pub async fn git_fetch(
+    app: tauri::AppHandle,
+    repo_path: String,
+    options: Option<FetchOptions>,
+) -> Result<(), GrovrError> {
+    let options = options.unwrap_or(FetchOptions { prune: true, ..Default::default() });
+    let args = fetch_args(&options)?;
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let op = Operation::start(&app, OperationKind::Fetch, &repo_path);
+    let result = op.git(&repo_path, &args).await;
+    op.finish(result).map(|_| ())
 }
 
+/// Fetches just the remote branch a new worktree will be based on, e.g.
+/// `origin/main`. Returns false without fetching when `base_branch` is not a
+/// remote branch.
 #[tauri::command]
-pub async fn git_pull(worktree_path: String) -> Result<(), String> {
-    tokio::task::spawn_blocking(move || {
-        let output = Command::new("git")
-            .args(["pull"])
-            .current_dir(&worktree_path)
-            .output()
-            .map_err(|e| format!("Failed to run git: {}", e))?;
+pub async fn fetch_base_branch(
+    app: tauri::AppHandle,
+    repo_path: String,
+    base_branch: String,
+) -> Result<bool, GrovrError> {
+    let Some((remote, branch)) = split_remote_branch(&repo_path, &base_branch)? else {
+        return Ok(false);
+    };
+    let op = Operation::start(&app, OperationKind::Fetch, &repo_path);
+    let result = fetch_remote_branch(&op, &repo_path, &remote, &branch).await;
+    op.finish(result).map(|_| true)
+}
 
-        if !output.status.success() {
-            return Err(String::from_utf8_lossy(&output.stderr).to_string());
-        }
+async fn fetch_remote_branch(op: &Operation, repo_path: &str, remote: &str, branch: &str) -> Result<(), GrovrError> {
+    // Explicit destination, so the remote-tracking branch moves even when the
+    // remote's configured refspec does not cover it
+    let refspec = format!("+refs/heads/{0}:refs/remotes/{1}/{0}", branch, remote);
+    op.git(repo_path, &["fetch", "--progress", remote, &refspec]).await?;
+    Ok(())
+}
 
-        Ok(())
-    })
-    .await
-    .map_err(|e| format!("Task failed: {}", e))?
+/// Splits `origin/feature/x` into the remote and branch name. Remote names may
+/// contain slashes, so the longest matching remote wins.
+fn split_remote_branch(repo_path: &str, name: &str) -> Result<Option<(String, String)>, GrovrError> {
+    let repo = open_repo(repo_path)?;
+    let remotes = repo.remotes()?;
+    let split = remotes
+        .iter()
+        .flatten()
+        .filter_map(|remote| {
+            let branch = name.strip_prefix(remote)?.strip_prefix('/')?;
+            Some((remote.to_string(), branch.to_string()))
+        })
+        .filter(|(_, branch)| !branch.is_empty())
+        .max_by_key(|(remote, _)| remote.len());
+    Ok(split)
+}
+
+#[tauri::command]
+pub async fn git_pull(app: tauri::AppHandle, worktree_path: String) -> Result<(), GrovrError> {
+    let op = Operation::start(&app, OperationKind::Pull, &worktree_path);
+    let result = op.git(&worktree_path, &["pull", "--progress"]).await;
+    op.finish(result).map(|_| ())
 }
 
 // ============ Remote Info ============
@@ -439,18 +1718,12 @@ pub struct GitHubRemoteInfo {
 }
 
 #[tauri::command]
-pub fn get_github_remote_info(repo_path: String, github_host: Option<String>) -> Result<Option<GitHubRemoteInfo>, String> {
-    let output = Command::new("git")
-        .args(["remote", "get-url", "origin"])
-        .current_dir(&repo_path)
-        .output()
-        .map_err(|e| format!("Failed to run git: {}", e))?;
-
-    if !output.status.success() {
-        return Ok(None);
-    }
-
-    let url = String::from_utf8_lossy(&output.stdout).trim().to_string();
+pub fn get_github_remote_info(repo_path: String, github_host: Option<String>) -> Result<Option<GitHubRemoteInfo>, GrovrError> {
+    let url = match run_git(&repo_path, &["remote", "get-url", "origin"]) {
+        Ok(stdout) => stdout.trim().to_string(),
+        Err(GrovrError::Git { .. }) => return Ok(None),
+        Err(e) => return Err(e),
+    };
 
     let hosts: Vec<&str> = match github_host.as_deref() {
         Some(h) if !h.is_empty() && h != "github.com" => vec!["github.com", h],
@@ -482,7 +1755,7 @@ pub fn get_github_remote_info(repo_path: String, github_host: Option<String>) ->
 // ============ IDE/File Operations ============
 
 #[tauri::command]
-pub fn open_ide(path: String, ide_preset: String, custom_command: Option<String>) -> Result<(), String> {
+pub fn open_ide(path: String, ide_preset: String, custom_command: Option<String>) -> Result<(), GrovrError> {
     let is_custom = ide_preset == "custom";
     let command = match ide_preset.as_str() {
         "code" => "code",
@@ -492,7 +1765,7 @@ pub fn open_ide(path: String, ide_preset: String, custom_command: Option<String>
         "pycharm" => "pycharm",
         "goland" => "goland",
         "custom" => custom_command.as_deref().ok_or("No custom command provided")?,
-        _ => return Err(format!("Unknown IDE preset: {}", ide_preset)),
+        _ => return Err(format!("Unknown IDE preset: {}", ide_preset).into()),
     };
 
     // Use login shell to access user's PATH environment
@@ -521,7 +1794,7 @@ pub fn open_ide(path: String, ide_preset: String, custom_command: Option<String>
             } else {
                 format!("Command exited with status: {}", output.status)
             };
-            return Err(error_msg);
+            return Err(error_msg.into());
         }
     } else {
         // For preset IDEs, spawn without waiting (they stay open)
@@ -535,7 +1808,7 @@ pub fn open_ide(path: String, ide_preset: String, custom_command: Option<String>
 }
 
 #[tauri::command]
-pub fn open_in_finder(path: String) -> Result<(), String> {
+pub fn open_in_finder(path: String) -> Result<(), GrovrError> {
     #[cfg(target_os = "macos")]
     {
         Command::new("open")
@@ -564,7 +1837,7 @@ pub fn open_in_finder(path: String) -> Result<(), String> {
 }
 
 #[tauri::command]
-pub fn open_terminal(path: String) -> Result<(), String> {
+pub fn open_terminal(path: String) -> Result<(), GrovrError> {
     #[cfg(target_os = "macos")]
     {
         Command::new("open")
@@ -600,65 +1873,298 @@ pub fn open_terminal(path: String) -> Result<(), String> {
                 return Ok(());
             }
         }
-        return Err("No supported terminal emulator found".to_string());
+        return Err("No supported terminal emulator found".into());
     }
 
     Ok(())
 }
 
+#[derive(Debug, Serialize, Clone)]
+pub struct CopyReportEntry {
+    /// Path relative to the worktree root, or the pattern for entries without matches
+    pub path: String,
+    pub mode: CopyMode,
+    /// Why the path was skipped or how it failed
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct CopyReport {
+    pub copied: Vec<CopyReportEntry>,
+    pub skipped: Vec<CopyReportEntry>,
+    pub failed: Vec<CopyReportEntry>,
+}
+
+/// Copies untracked files such as `.env` from the main worktree into a new one.
+///
+/// Entries may be literal paths or globs (`**/.env*`). Existing files in the
+/// target are never overwritten, and one failing path does not stop the rest.
 #[tauri::command]
-pub fn copy_paths_to_worktree(
+pub async fn copy_paths_to_worktree(
     source_path: String,
     target_path: String,
-    paths: Vec<String>,
-) -> Result<(), String> {
-    for rel_path in paths {
-        let src = Path::new(&source_path).join(&rel_path);
-        let dst = Path::new(&target_path).join(&rel_path);
+    paths: Vec<CopyPathSpec>,
+) -> Result<CopyReport, GrovrError> {
+    tokio::task::spawn_blocking(move || {
+        Ok(copy_paths(
+            Path::new(&source_path),
+            Path::new(&target_path),
+            &paths,
+        ))
+    })
+    .await?
+}
 
-        if !src.exists() {
-            continue; // Skip if source doesn't exist
-        }
+fn copy_paths(source: &Path, target: &Path, specs: &[CopyPathSpec]) -> CopyReport {
+    let mut report = CopyReport::default();
+    let global_excludes: Vec<&str> = specs
+        .iter()
+        .filter_map(|s| s.pattern().strip_prefix('!'))
+        .collect();
+
+    for spec in specs.iter().filter(|s| !s.pattern().starts_with('!')) {
+        let mode = spec.mode();
+        let pattern = spec.pattern().trim().trim_start_matches("./");
+        let report_pattern = |reason: String| CopyReportEntry {
+            path: pattern.to_string(),
+            mode,
+            reason: Some(reason),
+        };
+
+        let excludes = global_excludes
+            .iter()
+            .copied()
+            .chain(spec.exclude().iter().map(String::as_str));
+        let excludes = match exclude_set(excludes) {
+            Ok(set) => set,
+            Err(e) => {
+                report.failed.push(report_pattern(e.to_string()));
+                continue;
+            }
+        };
+
+        let matches = if pattern.contains(['*', '?', '[', '{']) {
+            match glob_matches(source, pattern, &excludes) {
+                Ok(matches) => matches,
+                Err(e) => {
+                    report.failed.push(report_pattern(e.to_string()));
+                    continue;
+                }
+            }
+        } else if source.join(pattern).symlink_metadata().is_ok() {
+            vec![PathBuf::from(pattern)]
+        } else {
+            Vec::new()
+        };
 
-        // Create parent directory if needed
-        if let Some(parent) = dst.parent() {
-            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        if matches.is_empty() {
+            report.skipped.push(report_pattern(
+                "Nothing matches in the source worktree".to_string(),
+            ));
+        }
+        for rel in matches {
+            copy_entry(source, target, &rel, mode, &excludes, &mut report);
         }
+    }
+
+    report
+}
 
-        if src.is_dir() {
-            copy_dir_recursive(&src, &dst)?;
+/// Excludes without a `/` match at any depth, like `.gitignore` entries.
+fn exclude_set<'a>(patterns: impl Iterator<Item = &'a str>) -> Result<GlobSet, globset::Error> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let pattern = pattern.trim().trim_start_matches("./");
+        let pattern = if pattern.contains('/') {
+            pattern.to_string()
         } else {
-            std::fs::copy(&src, &dst).map_err(|e| e.to_string())?;
+            format!("**/{}", pattern)
+        };
+        builder.add(GlobBuilder::new(&pattern).literal_separator(true).build()?);
+    }
+    builder.build()
+}
+
+/// Walks `source` without following symlinks and returns the relative paths
+/// matching `pattern`. Matched directories are returned whole, and `.git`,
+/// nested repositories or worktrees and excluded directories are not entered.
+fn glob_matches(
+    source: &Path,
+    pattern: &str,
+    excludes: &GlobSet,
+) -> Result<Vec<PathBuf>, globset::Error> {
+    let glob = GlobBuilder::new(pattern)
+        .literal_separator(true)
+        .build()?
+        .compile_matcher();
+    let mut matches = Vec::new();
+
+    let mut walk = WalkDir::new(source)
+        .min_depth(1)
+        .follow_links(false)
+        .into_iter();
+    while let Some(entry) = walk.next() {
+        let Ok(entry) = entry else { continue };
+        let Ok(rel) = entry.path().strip_prefix(source) else {
+            continue;
+        };
+        let is_dir = entry.file_type().is_dir();
+
+        let skip = rel == Path::new(".git")
+            || excludes.is_match(rel)
+            || (is_dir && entry.path().join(".git").symlink_metadata().is_ok());
+        if skip || glob.is_match(rel) {
+            if !skip {
+                matches.push(rel.to_path_buf());
+            }
+            if is_dir {
+                walk.skip_current_dir();
+            }
         }
     }
 
-    Ok(())
+    Ok(matches)
 }
 
-fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<(), String> {
-    std::fs::create_dir_all(dst).map_err(|e| e.to_string())?;
+fn copy_entry(
+    source: &Path,
+    target: &Path,
+    rel: &Path,
+    mode: CopyMode,
+    excludes: &GlobSet,
+    report: &mut CopyReport,
+) {
+    let src = source.join(rel);
+    let dst = target.join(rel);
+    let entry = |reason: Option<String>| CopyReportEntry {
+        path: rel.to_string_lossy().to_string(),
+        mode,
+        reason,
+    };
+
+    if dst.symlink_metadata().is_ok() {
+        report
+            .skipped
+            .push(entry(Some("Already exists in the worktree".to_string())));
+        return;
+    }
+    if let Some(parent) = dst.parent()
+        && let Err(e) = std::fs::create_dir_all(parent)
+    {
+        report.failed.push(entry(Some(e.to_string())));
+        return;
+    }
+
+    let is_dir = src.symlink_metadata().map(|m| m.is_dir()).unwrap_or(false);
+    let result = match mode {
+        CopyMode::Symlink => symlink(&src, &dst),
+        _ if is_dir => {
+            transfer_dir(source, &src, &dst, mode, excludes, report);
+            Ok(())
+        }
+        _ => transfer_file(&src, &dst, mode),
+    };
 
-    for entry in std::fs::read_dir(src).map_err(|e| e.to_string())? {
-        let entry = entry.map_err(|e| e.to_string())?;
-        let src_path = entry.path();
-        let dst_path = dst.join(entry.file_name());
+    match result {
+        Ok(()) => report.copied.push(entry(None)),
+        Err(e) => report.failed.push(entry(Some(e.to_string()))),
+    }
+}
+
+/// Recreates a directory tree file by file. Failures are reported per file.
+fn transfer_dir(
+    source: &Path,
+    src: &Path,
+    dst: &Path,
+    mode: CopyMode,
+    excludes: &GlobSet,
+    report: &mut CopyReport,
+) {
+    let mut walk = WalkDir::new(src).follow_links(false).into_iter();
+    while let Some(entry) = walk.next() {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                let path = e
+                    .path()
+                    .and_then(|p| p.strip_prefix(source).ok())
+                    .unwrap_or(src);
+                report.failed.push(CopyReportEntry {
+                    path: path.to_string_lossy().to_string(),
+                    mode,
+                    reason: Some(e.to_string()),
+                });
+                continue;
+            }
+        };
+        let rel = entry.path().strip_prefix(source).unwrap_or(entry.path());
+        if entry.depth() > 0 && excludes.is_match(rel) {
+            if entry.file_type().is_dir() {
+                walk.skip_current_dir();
+            }
+            continue;
+        }
 
-        if src_path.is_dir() {
-            copy_dir_recursive(&src_path, &dst_path)?;
+        let to = dst.join(entry.path().strip_prefix(src).unwrap_or(entry.path()));
+        let result = if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&to)
         } else {
-            std::fs::copy(&src_path, &dst_path).map_err(|e| e.to_string())?;
+            transfer_file(entry.path(), &to, mode)
+        };
+        if let Err(e) = result {
+            report.failed.push(CopyReportEntry {
+                path: rel.to_string_lossy().to_string(),
+                mode,
+                reason: Some(e.to_string()),
+            });
         }
     }
+}
 
-    Ok(())
+fn transfer_file(src: &Path, dst: &Path, mode: CopyMode) -> std::io::Result<()> {
+    // Recreate symlinks as links instead of copying what they point at
+    if src.symlink_metadata()?.file_type().is_symlink() {
+        return symlink(&std::fs::read_link(src)?, dst);
+    }
+    match mode {
+        CopyMode::Hardlink => std::fs::hard_link(src, dst),
+        CopyMode::Reflink => reflink_copy::reflink_or_copy(src, dst).map(|_| ()),
+        CopyMode::Copy | CopyMode::Symlink => std::fs::copy(src, dst).map(|_| ()),
+    }
+}
+
+#[cfg(unix)]
+fn symlink(original: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(original, link)
+}
+
+#[cfg(windows)]
+fn symlink(original: &Path, link: &Path) -> std::io::Result<()> {
+    if original.is_dir() {
+        std::os::windows::fs::symlink_dir(original, link)
+    } else {
+        std::os::windows::fs::symlink_file(original, link)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::DEFAULT_WORKTREE_TEMPLATE;
     use std::fs;
     use tempfile::TempDir;
 
+    // The command needs an AppHandle; tests run the same steps untracked
+    async fn create_worktree(
+        repo_path: String,
+        worktree_path: String,
+        branch_name: String,
+        base_branch: String,
+    ) -> Result<(), GrovrError> {
+        let op = Operation::untracked(OperationKind::CreateWorktree, &worktree_path);
+        add_worktree(&op, &repo_path, &worktree_path, &branch_name, &base_branch).await
+    }
+
     fn setup_test_repo() -> (TempDir, String) {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
         let repo_path = temp_dir.path().join("repo");
@@ -666,7 +2172,7 @@ mod tests {
 
         // Initialize git repo
         Command::new("git")
-            .args(["init"])
+            .args(["init", "-b", "main"])
             .current_dir(&repo_path)
             .output()
             .expect("Failed to init git repo");
@@ -710,8 +2216,8 @@ mod tests {
         assert!(worktrees[0].is_main);
     }
 
-    #[test]
-    fn test_create_and_get_worktree() {
+    #[tokio::test]
+    async fn test_create_and_get_worktree() {
         let (temp_dir, repo_path) = setup_test_repo();
         let worktree_path = temp_dir.path().join("worktrees/feature-test");
 
@@ -722,6 +2228,7 @@ mod tests {
             "feature-test".to_string(),
             "main".to_string(),
         )
+        .await
         .expect("Failed to create worktree");
 
         // Verify worktree exists
@@ -734,7 +2241,42 @@ mod tests {
     }
 
     #[test]
-    fn test_create_worktree_existing_branch() {
+    fn test_get_worktrees_detached_and_prunable() {
+        let (temp_dir, repo_path) = setup_test_repo();
+        let detached_path = temp_dir.path().join("worktrees/detached");
+        let gone_path = temp_dir.path().join("worktrees/gone");
+
+        Command::new("git")
+            .args(["worktree", "add", "--detach", &detached_path.to_string_lossy()])
+            .current_dir(&repo_path)
+            .output()
+            .expect("Failed to add detached worktree");
+        Command::new("git")
+            .args(["worktree", "add", "-b", "gone", &gone_path.to_string_lossy()])
+            .current_dir(&repo_path)
+            .output()
+            .expect("Failed to add worktree");
+        fs::remove_dir_all(&gone_path).expect("Failed to remove worktree dir");
+
+        let worktrees = get_worktrees(repo_path).expect("Failed to get worktrees");
+        assert_eq!(worktrees.len(), 3);
+        assert!(worktrees[0].is_main);
+        assert!(!worktrees[0].is_detached);
+        assert_eq!(worktrees[0].branch, "main");
+
+        let detached = worktrees.iter().find(|w| w.path.ends_with("detached")).unwrap();
+        assert!(detached.is_detached);
+        assert!(detached.branch.is_empty());
+        assert_eq!(detached.head, worktrees[0].head);
+        assert!(!detached.is_prunable);
+
+        let gone = worktrees.iter().find(|w| w.branch == "gone").unwrap();
+        assert!(gone.is_prunable);
+        assert!(gone.prunable_reason.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_create_worktree_existing_branch() {
         let (temp_dir, repo_path) = setup_test_repo();
 
         // Create a branch first
@@ -752,6 +2294,7 @@ mod tests {
             worktree_path.to_string_lossy().to_string(),
             "existing-branch".to_string(),
         )
+        .await
         .expect("Failed to create worktree from existing branch");
 
         // Verify
@@ -762,8 +2305,8 @@ mod tests {
         assert!(existing_wt.is_some());
     }
 
-    #[test]
-    fn test_remove_worktree() {
+    #[tokio::test]
+    async fn test_remove_worktree() {
         let (temp_dir, repo_path) = setup_test_repo();
         let worktree_path = temp_dir.path().join("worktrees/to-delete");
 
@@ -774,6 +2317,7 @@ mod tests {
             "to-delete".to_string(),
             "main".to_string(),
         )
+        .await
         .expect("Failed to create worktree");
 
         // Verify it exists
@@ -787,7 +2331,11 @@ mod tests {
             false,
             false,
             None,
+            false,
+            false,
+            None,
         )
+        .await
         .expect("Failed to remove worktree");
 
         // Verify it's gone
@@ -795,8 +2343,8 @@ mod tests {
         assert_eq!(worktrees.len(), 1);
     }
 
-    #[test]
-    fn test_remove_worktree_force() {
+    #[tokio::test]
+    async fn test_remove_worktree_force() {
         let (temp_dir, repo_path) = setup_test_repo();
         let worktree_path = temp_dir.path().join("worktrees/dirty-wt");
 
@@ -807,6 +2355,7 @@ mod tests {
             "dirty-wt".to_string(),
             "main".to_string(),
         )
+        .await
         .expect("Failed to create worktree");
 
         // Make it dirty (uncommitted changes)
@@ -819,8 +2368,12 @@ mod tests {
             false,
             false,
             None,
-        );
-        assert!(result.is_err());
+            false,
+            false,
+            None,
+        )
+        .await;
+        assert!(matches!(result, Err(GrovrError::DirtyWorktree { .. })));
 
         // Remove with force - should succeed
         remove_worktree(
@@ -829,7 +2382,11 @@ mod tests {
             true,
             false,
             None,
+            false,
+            false,
+            None,
         )
+        .await
         .expect("Failed to force remove worktree");
 
         // Verify it's gone
@@ -837,11 +2394,190 @@ mod tests {
         assert_eq!(worktrees.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_plan_remove_worktree() {
+        let (temp_dir, repo_path) = setup_test_repo();
+        let worktree_path = temp_dir.path().join("worktrees/wip").to_string_lossy().to_string();
+
+        create_worktree(repo_path.clone(), worktree_path.clone(), "wip".to_string(), "main".to_string())
+            .await
+            .expect("Failed to create worktree");
+        fs::write(Path::new(&worktree_path).join("feature.txt"), "feature").unwrap();
+        run_git(&worktree_path, &["add", "-A"]).unwrap();
+        run_git(&worktree_path, &["commit", "-m", "Add feature"]).unwrap();
+        fs::write(Path::new(&worktree_path).join("stash.txt"), "stash").unwrap();
+        run_git(&worktree_path, &["stash", "push", "-u", "-m", "parked"]).unwrap();
+        fs::write(Path::new(&worktree_path).join("dirty.txt"), "dirty").unwrap();
+
+        let plan = plan_remove_worktree(repo_path.clone(), worktree_path.clone())
+            .await
+            .expect("Failed to plan removal");
+        assert_eq!(plan.branch.as_deref(), Some("wip"));
+        assert_eq!(plan.dirty_files, vec!["dirty.txt".to_string()]);
+        // No remotes, so the whole history counts as unpushed
+        assert_eq!(plan.unpushed_count, 2);
+        assert_eq!(plan.unpushed_commits[0].summary, "Add feature");
+        assert_eq!(plan.stashes.len(), 1);
+        // Without an origin there is no default branch to compare against
+        assert_eq!(plan.branch_merged, None);
+
+        // Safe mode refuses until the current plan is acknowledged
+        let result = remove_worktree(
+            repo_path.clone(),
+            worktree_path.clone(),
+            true,
+            true,
+            Some("wip".to_string()),
+            false,
+            true,
+            Some("stale".to_string()),
+        )
+        .await;
+        assert!(matches!(result, Err(GrovrError::RemovalNotAcknowledged { .. })));
+
+        remove_worktree(repo_path.clone(), worktree_path, true, true, Some("wip".to_string()), false, true, Some(plan.plan_id))
+            .await
+            .expect("Failed to remove acknowledged worktree");
+        assert_eq!(get_worktrees(repo_path).unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_locked_worktree_requires_override() {
+        let (temp_dir, repo_path) = setup_test_repo();
+        let worktree_path = temp_dir.path().join("worktrees/usb-drive");
+        let worktree_path = worktree_path.to_string_lossy().to_string();
+
+        create_worktree(repo_path.clone(), worktree_path.clone(), "usb-drive".to_string(), "main".to_string())
+            .await
+            .expect("Failed to create worktree");
+        lock_worktree(repo_path.clone(), worktree_path.clone(), Some("on removable drive".to_string()))
+            .expect("Failed to lock worktree");
+
+        let worktrees = get_worktrees(repo_path.clone()).expect("Failed to get worktrees");
+        let locked = worktrees.iter().find(|w| w.branch == "usb-drive").unwrap();
+        assert!(locked.is_locked);
+        assert_eq!(locked.lock_reason.as_deref(), Some("on removable drive"));
+
+        // Removing or pruning without the override leaves it alone
+        let result = remove_worktree(repo_path.clone(), worktree_path.clone(), true, false, None, false, false, None).await;
+        assert!(matches!(result, Err(GrovrError::WorktreeLocked { .. })));
+        prune_worktrees(repo_path.clone(), false).expect("Failed to prune");
+        assert_eq!(get_worktrees(repo_path.clone()).unwrap().len(), 2);
+
+        remove_worktree(repo_path.clone(), worktree_path, false, false, None, true, false, None)
+            .await
+            .expect("Failed to remove locked worktree");
+        assert_eq!(get_worktrees(repo_path).unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_move_worktree_dir() {
+        let (temp_dir, repo_path) = setup_test_repo();
+        let from = temp_dir.path().join("worktrees/feature");
+        let to = temp_dir.path().join("moved/feature");
+
+        create_worktree(
+            repo_path.clone(),
+            from.to_string_lossy().to_string(),
+            "feature".to_string(),
+            "main".to_string(),
+        )
+        .await
+        .expect("Failed to create worktree");
+
+        let (old_key, new_key) = move_worktree_dir(&repo_path, &from.to_string_lossy(), &to.to_string_lossy())
+            .expect("Failed to move worktree");
+        assert_eq!(old_key, display_path(&from));
+        assert_eq!(new_key, display_path(&to));
+        assert!(!from.exists());
+
+        let worktrees = get_worktrees(repo_path).expect("Failed to get worktrees");
+        let moved = worktrees.iter().find(|w| w.branch == "feature").unwrap();
+        assert_eq!(moved.path, new_key);
+
+        let mut memos = HashMap::new();
+        memos.insert(old_key.clone(), WorktreeMemo { description: Some("desc".to_string()), ..Default::default() });
+        rekey_memo(&mut memos, &old_key, &new_key);
+        assert!(!memos.contains_key(&old_key));
+        assert_eq!(memos[&new_key].description.as_deref(), Some("desc"));
+    }
+
+    fn vars<'a>(branch: &'a str, description: &'a str, issue: &'a str) -> TemplateVars<'a> {
+        TemplateVars { repo_path: "/src/app", branch, description, issue }
+    }
+
+    #[test]
+    fn test_expand_template() {
+        let expand = |template: &str, vars: &TemplateVars| expand_template(template, vars).unwrap();
+
+        assert_eq!(
+            expand(DEFAULT_WORKTREE_TEMPLATE, &vars("feat-x", "Fix login (v2)", "")),
+            "/src/app.worktrees/feat-x-Fix-login-_v2_"
+        );
+        assert_eq!(expand(DEFAULT_WORKTREE_TEMPLATE, &vars("feat-x", "", "")), "/src/app.worktrees/feat-x");
+
+        // Branch slashes are flattened instead of nesting directories
+        assert_eq!(
+            expand(DEFAULT_WORKTREE_TEMPLATE, &vars("feature/login", "", "")),
+            "/src/app.worktrees/feature-login"
+        );
+        assert_eq!(
+            expand("{repo_parent}/wt/{issue}/{slug:description}", &vars("b", "Fix Login: (v2) ", "ABC-12")),
+            "/src/wt/ABC-12/fix-login-v2"
+        );
+
+        // Empty values do not leave empty components or dangling dashes
+        assert_eq!(expand("{project}.wt/{issue}/{issue}-{branch}", &vars("b", "", "")), "/src/app.wt/b");
+
+        // Relative templates land next to the repository
+        assert_eq!(expand("worktrees/{branch}", &vars("b", "", "")), "/src/worktrees/b");
+
+        let dated = expand("{project}.wt/{date}", &vars("b", "", ""));
+        let date = dated.rsplit('/').next().unwrap();
+        assert_eq!(date.len(), 10);
+        assert_eq!(&date[4..5], "-");
+
+        assert!(expand_template("{project}/{nope}", &vars("b", "", "")).is_err());
+    }
+
     #[test]
-    fn test_get_worktree_status_clean() {
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_path_collisions() {
+        let (_temp_dir, repo_path) = setup_test_repo();
+        let template = "{project}.worktrees/{branch}";
+        let existing = format!("{}.worktrees/taken", repo_path);
+
+        create_worktree(repo_path.clone(), existing.clone(), "taken".to_string(), "main".to_string())
+            .await
+            .expect("Failed to create worktree");
+        fs::create_dir_all(format!("{}.worktrees/taken-2", repo_path)).unwrap();
+        fs::create_dir_all(format!("{}.worktrees/blocked", repo_path)).unwrap();
+
+        let vars = |branch| TemplateVars { repo_path: &repo_path, branch, description: "", issue: "" };
+
+        let free = resolve_path(template, &vars("free")).unwrap();
+        assert_eq!(free.collision, None);
+        assert_eq!(free.available_path, free.path);
+
+        let taken = resolve_path(template, &vars("taken")).unwrap();
+        assert_eq!(taken.collision, Some(PathCollision::Worktree { branch: "taken".to_string() }));
+        assert_eq!(taken.available_path, format!("{}-3", existing));
+
+        let blocked = resolve_path(template, &vars("blocked")).unwrap();
+        assert_eq!(blocked.collision, Some(PathCollision::Directory));
+    }
+
+    #[tokio::test]
+    async fn test_get_worktree_status_clean() {
         let (_temp_dir, repo_path) = setup_test_repo();
 
-        let status = get_worktree_status(repo_path).expect("Failed to get status");
+        let status = get_worktree_status(repo_path).await.expect("Failed to get status");
 
         assert!(!status.has_changes);
         assert_eq!(status.staged, 0);
@@ -849,8 +2585,8 @@ mod tests {
         assert_eq!(status.untracked, 0);
     }
 
-    #[test]
-    fn test_get_worktree_status_dirty() {
+    #[tokio::test]
+    async fn test_get_worktree_status_dirty() {
         let (_temp_dir, repo_path) = setup_test_repo();
 
         // Create untracked file
@@ -859,13 +2595,141 @@ mod tests {
         // Create modified file
         fs::write(Path::new(&repo_path).join("README.md"), "# Modified").expect("Failed to modify");
 
-        let status = get_worktree_status(repo_path).expect("Failed to get status");
+        let status = get_worktree_status(repo_path).await.expect("Failed to get status");
 
         assert!(status.has_changes);
         assert_eq!(status.untracked, 1);
         assert_eq!(status.unstaged, 1);
     }
 
+    #[tokio::test]
+    async fn test_get_worktree_status_tracking() {
+        let (temp_dir, repo_path) = setup_test_repo();
+        let remote_path = temp_dir.path().join("remote.git");
+        let remote = remote_path.to_string_lossy().to_string();
+        let git = |args: &[&str]| {
+            Command::new("git").args(args).current_dir(&repo_path).output().expect("Failed to run git");
+        };
+
+        Command::new("git")
+            .args(["init", "--bare", &remote])
+            .output()
+            .expect("Failed to init remote");
+        git(&["remote", "add", "origin", &remote]);
+        git(&["push", "-u", "origin", "main"]);
+        git(&["remote", "set-head", "origin", "main"]);
+        git(&["checkout", "-b", "feature"]);
+        git(&["push", "-u", "origin", "feature"]);
+        fs::write(Path::new(&repo_path).join("feature.txt"), "feature").unwrap();
+        git(&["add", "-A"]);
+        git(&["commit", "-m", "Add feature"]);
+
+        let status = get_worktree_status(repo_path.clone()).await.expect("Failed to get status");
+        assert_eq!(status.upstream.as_deref(), Some("origin/feature"));
+        assert!(!status.upstream_gone);
+        assert_eq!((status.ahead, status.behind), (Some(1), Some(0)));
+        assert_eq!(status.base_branch.as_deref(), Some("origin/main"));
+        assert_eq!((status.ahead_of_base, status.behind_base), (Some(1), Some(0)));
+        assert_eq!(status.last_commit.unwrap().summary, "Add feature");
+
+        // Delete the branch on the remote and prune it locally
+        git(&["push", "origin", "--delete", "feature"]);
+        git(&["fetch", "--prune"]);
+
+        let status = get_worktree_status(repo_path).await.expect("Failed to get status");
+        assert!(status.upstream_gone);
+        assert_eq!(status.ahead, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_project_status() {
+        let (temp_dir, repo_path) = setup_test_repo();
+        let dirty_path = temp_dir.path().join("worktrees/dirty");
+        let gone_path = temp_dir.path().join("worktrees/gone");
+
+        for (path, branch) in [(&dirty_path, "dirty"), (&gone_path, "gone")] {
+            create_worktree(repo_path.clone(), path.to_string_lossy().to_string(), branch.to_string(), "main".to_string())
+                .await
+                .expect("Failed to create worktree");
+        }
+        fs::write(dirty_path.join("new.txt"), "new").unwrap();
+        fs::remove_dir_all(&gone_path).unwrap();
+
+        let statuses = get_project_status(repo_path.clone()).await.expect("Failed to get project status");
+        assert_eq!(statuses.len(), 3);
+        assert!(!statuses[&display_path(Path::new(&repo_path))].status.as_ref().unwrap().has_changes);
+        assert_eq!(statuses[&display_path(&dirty_path)].status.as_ref().unwrap().untracked, 1);
+
+        let gone = &statuses[&gone_path.to_string_lossy().to_string()];
+        assert!(gone.status.is_none());
+        assert!(gone.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_archive_and_restore_worktree() {
+        let (temp_dir, repo_path) = setup_test_repo();
+        let worktree_path = temp_dir.path().join("worktrees/parked").to_string_lossy().to_string();
+        let wt_dir = Path::new(&worktree_path);
+
+        create_worktree(repo_path.clone(), worktree_path.clone(), "parked".to_string(), "main".to_string())
+            .await
+            .expect("Failed to create worktree");
+        fs::write(wt_dir.join("committed.txt"), "committed").unwrap();
+        run_git(&worktree_path, &["add", "-A"]).unwrap();
+        run_git(&worktree_path, &["commit", "-m", "Commit before archiving"]).unwrap();
+        fs::write(wt_dir.join("README.md"), "# Edited").unwrap();
+        fs::write(wt_dir.join("untracked.txt"), "untracked").unwrap();
+        fs::remove_file(wt_dir.join("committed.txt")).unwrap();
+
+        let archived = archive_worktree_dir(&repo_path, &worktree_path, None).expect("Failed to archive");
+        assert_eq!(archived.archive_ref, "refs/grovr/archive/parked");
+        assert!(archived.has_changes);
+        assert!(!wt_dir.exists());
+        assert!(!get_branches(repo_path.clone(), false).unwrap().iter().any(|b| b.name == "parked"));
+
+        restore_worktree_dir(&archived).expect("Failed to restore");
+        let restored = find_worktree(&repo_path, &worktree_path).unwrap().expect("Worktree not restored");
+        assert_eq!(restored.branch, "parked");
+        assert_eq!(restored.head.as_deref(), Some(archived.head.as_str()));
+        assert_eq!(fs::read_to_string(wt_dir.join("README.md")).unwrap(), "# Edited");
+        assert_eq!(fs::read_to_string(wt_dir.join("untracked.txt")).unwrap(), "untracked");
+        assert!(!wt_dir.join("committed.txt").exists());
+        assert!(open_repo(&repo_path).unwrap().find_reference(&archived.archive_ref).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_worktrees() {
+        let (temp_dir, repo_path) = setup_test_repo();
+        let done_path = temp_dir.path().join("worktrees/done").to_string_lossy().to_string();
+        let locked_path = temp_dir.path().join("worktrees/locked").to_string_lossy().to_string();
+
+        for (path, branch) in [(&done_path, "done"), (&locked_path, "locked")] {
+            create_worktree(repo_path.clone(), path.clone(), branch.to_string(), "main".to_string())
+                .await
+                .expect("Failed to create worktree");
+        }
+        lock_worktree(repo_path.clone(), locked_path.clone(), None).expect("Failed to lock worktree");
+
+        let results = cleanup_worktrees(
+            repo_path.clone(),
+            vec![done_path.clone(), locked_path.clone(), repo_path.clone()],
+            false,
+            true,
+        )
+        .await
+        .expect("Failed to clean up worktrees");
+
+        assert!(results[0].removed);
+        assert_eq!(results[0].branch.as_deref(), Some("done"));
+        assert!(matches!(results[1].error, Some(GrovrError::WorktreeLocked { .. })));
+        assert!(!results[2].removed);
+
+        let branches = get_branches(repo_path.clone(), false).unwrap();
+        assert!(!branches.iter().any(|b| b.name == "done"));
+        assert!(branches.iter().any(|b| b.name == "locked"));
+        assert_eq!(get_worktrees(repo_path).unwrap().len(), 2);
+    }
+
     #[test]
     fn test_get_branches() {
         let (_temp_dir, repo_path) = setup_test_repo();
@@ -934,4 +2798,141 @@ mod tests {
         let branches = get_branches(repo_path, false).expect("Failed to get branches");
         assert!(!branches.iter().any(|b| b.name == "to-delete"));
     }
+
+    #[tokio::test]
+    async fn test_copy_paths_to_worktree() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let source = temp_dir.path().join("source");
+        let target = temp_dir.path().join("target");
+        for dir in ["api", "web/node_modules/pkg", "vendor/lib/.git", "cache"] {
+            fs::create_dir_all(source.join(dir)).expect("Failed to create dir");
+        }
+        for file in [".env", "api/.env.local", "web/.env", "web/node_modules/pkg/.env", "vendor/lib/.env"] {
+            fs::write(source.join(file), file).expect("Failed to write file");
+        }
+        fs::write(source.join("cache/data.bin"), "data").expect("Failed to write file");
+        fs::create_dir_all(target.join("api")).expect("Failed to create dir");
+        fs::write(target.join("api/.env.local"), "existing").expect("Failed to write file");
+
+        let report = copy_paths_to_worktree(
+            source.to_string_lossy().to_string(),
+            target.to_string_lossy().to_string(),
+            vec![
+                CopyPathSpec::Pattern("**/.env*".to_string()),
+                CopyPathSpec::Pattern("!node_modules".to_string()),
+                CopyPathSpec::Entry {
+                    pattern: "cache".to_string(),
+                    mode: CopyMode::Symlink,
+                    exclude: Vec::new(),
+                },
+                CopyPathSpec::Pattern("missing/*.json".to_string()),
+            ],
+        )
+        .await
+        .expect("Failed to copy paths");
+
+        let mut copied: Vec<&str> = report.copied.iter().map(|e| e.path.as_str()).collect();
+        copied.sort();
+        assert_eq!(copied, vec![".env", "cache", "web/.env"]);
+        assert!(report.failed.is_empty());
+
+        // Existing files are kept, patterns without matches are reported
+        let skipped: Vec<&str> = report.skipped.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(skipped, vec!["api/.env.local", "missing/*.json"]);
+        assert_eq!(fs::read_to_string(target.join("api/.env.local")).unwrap(), "existing");
+
+        // Excluded and nested repository files are left alone
+        assert!(!target.join("web/node_modules").exists());
+        assert!(!target.join("vendor").exists());
+
+        let link = fs::symlink_metadata(target.join("cache")).expect("Symlink not created");
+        assert!(link.file_type().is_symlink());
+        assert_eq!(fs::read_to_string(target.join("cache/data.bin")).unwrap(), "data");
+    }
+
+    #[test]
+    fn test_checkout_pull_request_from_fork() {
+        let (temp_dir, repo_path) = setup_test_repo();
+        let git = |args: &[&str]| run_git(&repo_path, args).expect("git failed");
+
+        // Publish a commit only under the PR ref, as GitHub does for forks
+        let origin = temp_dir.path().join("origin.git");
+        let origin_str = origin.to_string_lossy().to_string();
+        Command::new("git").args(["init", "--bare", &origin_str]).output().expect("Failed to init origin");
+        git(&["remote", "add", "origin", &origin_str]);
+        git(&["push", "origin", "main"]);
+        git(&["commit", "--allow-empty", "-m", "Fork change"]);
+        let pr_sha = git(&["rev-parse", "HEAD"]).trim().to_string();
+        git(&["push", "origin", "HEAD:refs/pull/7/head"]);
+        git(&["reset", "--hard", "HEAD~1"]);
+
+        let pr = PullRequestHead {
+            number: 7,
+            title: "Fix typo".to_string(),
+            state: "open".to_string(),
+            url: String::new(),
+            head_ref: "typo".to_string(),
+            head_sha: pr_sha.clone(),
+            head_repo: Some("alice/app".to_string()),
+            head_clone_url: Some("https://github.com/alice/app.git".to_string()),
+            is_fork: true,
+        };
+        let branch = pr_branch_name(&pr);
+        assert_eq!(branch, "alice/typo");
+
+        let worktree_path = temp_dir.path().join("pr-7").to_string_lossy().to_string();
+        let sha = checkout_pull_request(&repo_path, &worktree_path, &branch, &pr).expect("Failed to check out PR");
+        assert_eq!(sha, pr_sha);
+        assert_eq!(run_git(&worktree_path, &["rev-parse", "HEAD"]).unwrap().trim(), pr_sha);
+        assert_eq!(git(&["config", "branch.alice/typo.merge"]).trim(), "refs/heads/typo");
+
+        let deleted_fork = PullRequestHead { head_repo: None, head_clone_url: None, ..pr };
+        assert_eq!(pr_branch_name(&deleted_fork), "pr-7");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_base_branch_only() {
+        let (temp_dir, repo_path) = setup_test_repo();
+        let git = |args: &[&str]| run_git(&repo_path, args).expect("git failed");
+
+        let origin = temp_dir.path().join("origin.git");
+        let origin_str = origin.to_string_lossy().to_string();
+        Command::new("git").args(["init", "--bare", &origin_str]).output().expect("Failed to init origin");
+        git(&["remote", "add", "origin", &origin_str]);
+        git(&["push", "origin", "main", "main:other"]);
+
+        // Move both branches on the remote without updating the local tracking refs
+        git(&["commit", "--allow-empty", "-m", "Upstream change"]);
+        let new_sha = git(&["rev-parse", "HEAD"]).trim().to_string();
+        git(&["push", "--no-verify", &origin_str, "HEAD:main", "HEAD:other"]);
+        git(&["reset", "--hard", "HEAD~1"]);
+
+        assert_eq!(split_remote_branch(&repo_path, "main").unwrap(), None);
+        let (remote, branch) = split_remote_branch(&repo_path, "origin/main").unwrap().unwrap();
+        assert_eq!((remote.as_str(), branch.as_str()), ("origin", "main"));
+
+        let op = Operation::untracked(OperationKind::Fetch, &repo_path);
+        fetch_remote_branch(&op, &repo_path, &remote, &branch).await.expect("Failed to fetch");
+        assert_eq!(git(&["rev-parse", "origin/main"]).trim(), new_sha);
+        assert_ne!(git(&["rev-parse", "origin/other"]).trim(), new_sha);
+    }
+
+    #[test]
+    fn test_fetch_args() {
+        let args = fetch_args(&FetchOptions { prune: true, ..Default::default() }).unwrap();
+        assert_eq!(args, ["fetch", "--progress", "--prune", "--all"]);
+
+        let options = FetchOptions {
+            remote: Some("upstream".to_string()),
+            refspecs: vec!["main".to_string()],
+            depth: Some(1),
+            filter: Some("blob:none".to_string()),
+            prune: false,
+        };
+        let args = fetch_args(&options).unwrap();
+        assert_eq!(args, ["fetch", "--progress", "--depth=1", "--filter=blob:none", "upstream", "main"]);
+
+        let options = FetchOptions { refspecs: vec!["main".to_string()], ..Default::default() };
+        assert!(fetch_args(&options).is_err());
+    }
 }
@@ -0,0 +1,322 @@
+use crate::commands::operations::kill_process_tree;
+use crate::commands::settings::SettingsState;
+use crate::error::GrovrError;
+use crate::types::DEFAULT_HOOK_TIMEOUT_SECS;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Stdio;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{Emitter, State};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::process::Command;
+use tokio::sync::{mpsc, Notify};
+
+/// How long to keep reading output after a hook exits. Background processes
+/// started by a hook can hold its pipes open indefinitely.
+const DRAIN_GRACE: Duration = Duration::from_millis(200);
+
+/// Cancel handles of running hooks, keyed by worktree path.
+#[derive(Default)]
+pub struct HookState(pub Mutex<HashMap<String, Arc<Notify>>>);
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum HookStage {
+    PostCreate,
+    PreRemove,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum HookStream {
+    Stdout,
+    Stderr,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum HookStatus {
+    Succeeded,
+    Failed,
+    TimedOut,
+    Cancelled,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct HookOutcome {
+    pub command: String,
+    pub status: HookStatus,
+    /// `None` when the hook was killed or could not be started
+    pub exit_code: Option<i32>,
+    pub duration_ms: u64,
+}
+
+/// Payload of the `hook-started`, `hook-output` and `hook-finished` events.
+#[derive(Debug, Serialize, Clone)]
+pub struct HookEvent {
+    pub worktree_path: String,
+    pub stage: HookStage,
+    pub command: String,
+    pub stream: Option<HookStream>,
+    pub line: Option<String>,
+    /// Only set for `hook-finished`
+    pub outcome: Option<HookOutcome>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct HookRunResult {
+    pub stage: HookStage,
+    /// Hooks that ran, in order. Hooks after a failure are not run.
+    pub hooks: Vec<HookOutcome>,
+    pub success: bool,
+}
+
+/// Runs a project's hooks for `stage` in the worktree, streaming their output
+/// as events. Hooks run one at a time and stop at the first failure.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn run_worktree_hooks(
+    app: tauri::AppHandle,
+    settings: State<'_, SettingsState>,
+    hooks: State<'_, HookState>,
+    repo_path: String,
+    worktree_path: String,
+    stage: HookStage,
+    branch: String,
+    base_branch: Option<String>,
+    issue: Option<String>,
+) -> Result<HookRunResult, GrovrError> {
+    let config = {
+        let settings = settings.0.lock().map_err(|e| e.to_string())?;
+        settings.project_settings(&repo_path).hooks
+    };
+    let commands = match stage {
+        HookStage::PostCreate => config.post_create,
+        HookStage::PreRemove => config.pre_remove,
+    };
+    let timeout = Duration::from_secs(config.timeout_secs.unwrap_or(DEFAULT_HOOK_TIMEOUT_SECS));
+
+    let cancel = Arc::new(Notify::new());
+    {
+        let mut running = hooks.0.lock().map_err(|e| e.to_string())?;
+        if running.contains_key(&worktree_path) {
+            return Err(format!("Hooks are already running for '{}'", worktree_path).into());
+        }
+        running.insert(worktree_path.clone(), cancel.clone());
+    }
+
+    let project = Path::new(&repo_path)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let stage_name = match stage {
+        HookStage::PostCreate => "post_create",
+        HookStage::PreRemove => "pre_remove",
+    };
+    let envs = [
+        ("GROVR_HOOK", stage_name.to_string()),
+        ("GROVR_PROJECT", project),
+        ("GROVR_REPO_PATH", repo_path.clone()),
+        ("GROVR_WORKTREE_PATH", worktree_path.clone()),
+        ("GROVR_BRANCH", branch),
+        ("GROVR_BASE_BRANCH", base_branch.unwrap_or_default()),
+        ("GROVR_ISSUE", issue.unwrap_or_default()),
+    ];
+
+    let mut outcomes = Vec::new();
+    for command in commands.iter().filter(|c| !c.trim().is_empty()) {
+        let event = |stream: Option<HookStream>, line: Option<String>, outcome: Option<HookOutcome>| HookEvent {
+            worktree_path: worktree_path.clone(),
+            stage,
+            command: command.clone(),
+            stream,
+            line,
+            outcome,
+        };
+
+        let _ = app.emit("hook-started", event(None, None, None));
+        let outcome = run_hook(command, &worktree_path, &envs, timeout, &cancel, |stream, line| {
+            let _ = app.emit("hook-output", event(Some(stream), Some(line), None));
+        })
+        .await;
+        let _ = app.emit("hook-finished", event(None, None, Some(outcome.clone())));
+
+        let succeeded = outcome.status == HookStatus::Succeeded;
+        outcomes.push(outcome);
+        if !succeeded {
+            break;
+        }
+    }
+
+    if let Ok(mut running) = hooks.0.lock() {
+        running.remove(&worktree_path);
+    }
+
+    let success = outcomes.iter().all(|o| o.status == HookStatus::Succeeded);
+    Ok(HookRunResult { stage, hooks: outcomes, success })
+}
+
+/// Stops the hooks running in a worktree. Returns whether any were running.
+#[tauri::command]
+pub fn cancel_worktree_hooks(hooks: State<HookState>, worktree_path: String) -> Result<bool, GrovrError> {
+    let running = hooks.0.lock().map_err(|e| e.to_string())?;
+    match running.get(&worktree_path) {
+        Some(cancel) => {
+            cancel.notify_one();
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Runs one hook through the platform shell, passing each output line to `on_output`.
+async fn run_hook(
+    command: &str,
+    dir: &str,
+    envs: &[(&str, String)],
+    timeout: Duration,
+    cancel: &Notify,
+    mut on_output: impl FnMut(HookStream, String),
+) -> HookOutcome {
+    let started = Instant::now();
+    let outcome = |status, exit_code| HookOutcome {
+        command: command.to_string(),
+        status,
+        exit_code,
+        duration_ms: started.elapsed().as_millis() as u64,
+    };
+
+    let mut cmd = shell_command(command);
+    cmd.current_dir(dir)
+        .envs(envs.iter().map(|(k, v)| (*k, v)))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    // Own process group, so killing the hook also stops what it started
+    #[cfg(unix)]
+    cmd.process_group(0);
+
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            on_output(HookStream::Stderr, format!("Failed to start hook: {}", e));
+            return outcome(HookStatus::Failed, None);
+        }
+    };
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    if let Some(stdout) = child.stdout.take() {
+        forward_lines(stdout, HookStream::Stdout, tx.clone());
+    }
+    if let Some(stderr) = child.stderr.take() {
+        forward_lines(stderr, HookStream::Stderr, tx);
+    }
+
+    let deadline = tokio::time::sleep(timeout);
+    tokio::pin!(deadline);
+    let (status, exit_code) = loop {
+        tokio::select! {
+            Some((stream, line)) = rx.recv() => on_output(stream, line),
+            result = child.wait() => {
+                break match result {
+                    Ok(status) if status.success() => (HookStatus::Succeeded, status.code()),
+                    Ok(status) => (HookStatus::Failed, status.code()),
+                    Err(_) => (HookStatus::Failed, None),
+                };
+            }
+            _ = &mut deadline => {
+                kill_process_tree(&mut child).await;
+                on_output(HookStream::Stderr, format!("Timed out after {}s", timeout.as_secs()));
+                break (HookStatus::TimedOut, None);
+            }
+            _ = cancel.notified() => {
+                kill_process_tree(&mut child).await;
+                break (HookStatus::Cancelled, None);
+            }
+        }
+    };
+
+    while let Ok(Some((stream, line))) = tokio::time::timeout(DRAIN_GRACE, rx.recv()).await {
+        on_output(stream, line);
+    }
+
+    outcome(status, exit_code)
+}
+
+fn shell_command(command: &str) -> Command {
+    #[cfg(windows)]
+    {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    }
+    #[cfg(not(windows))]
+    {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    }
+}
+
+fn forward_lines(
+    reader: impl AsyncRead + Unpin + Send + 'static,
+    stream: HookStream,
+    tx: mpsc::UnboundedSender<(HookStream, String)>,
+) {
+    tokio::spawn(async move {
+        let mut lines = BufReader::new(reader).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            if tx.send((stream, line)).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    async fn run(command: &str, timeout: Duration) -> (HookOutcome, Vec<(HookStream, String)>) {
+        let dir = std::env::temp_dir();
+        let envs = [("GROVR_BRANCH", "feature/x".to_string())];
+        let mut output = Vec::new();
+        let outcome = run_hook(command, &dir.to_string_lossy(), &envs, timeout, &Notify::new(), |stream, line| {
+            output.push((stream, line))
+        })
+        .await;
+        (outcome, output)
+    }
+
+    #[tokio::test]
+    async fn test_run_hook_streams_output_and_env() {
+        let (outcome, output) = run("echo \"$GROVR_BRANCH\"; echo oops >&2", Duration::from_secs(10)).await;
+        assert_eq!(outcome.status, HookStatus::Succeeded);
+        assert_eq!(outcome.exit_code, Some(0));
+        assert!(output.contains(&(HookStream::Stdout, "feature/x".to_string())));
+        assert!(output.contains(&(HookStream::Stderr, "oops".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_run_hook_reports_failure_and_timeout() {
+        let (outcome, _) = run("exit 3", Duration::from_secs(10)).await;
+        assert_eq!(outcome.status, HookStatus::Failed);
+        assert_eq!(outcome.exit_code, Some(3));
+
+        let (outcome, _) = run("sleep 5", Duration::from_millis(100)).await;
+        assert_eq!(outcome.status, HookStatus::TimedOut);
+        assert!(outcome.duration_ms < 5000);
+    }
+
+    #[tokio::test]
+    async fn test_run_hook_cancel() {
+        let cancel = Notify::new();
+        cancel.notify_one();
+        let dir = std::env::temp_dir();
+        let outcome = run_hook("sleep 5", &dir.to_string_lossy(), &[], Duration::from_secs(10), &cancel, |_, _| {}).await;
+        assert_eq!(outcome.status, HookStatus::Cancelled);
+    }
+}
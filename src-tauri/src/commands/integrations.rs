@@ -1,4 +1,5 @@
 use crate::commands::settings::SettingsState;
+use crate::error::GrovrError;
 use crate::secure_store;
 use crate::types::{GitHubConfig, GitHubConfigMeta, JiraConfig, JiraConfigMeta};
 use base64::{engine::general_purpose::STANDARD, Engine};
@@ -42,7 +43,7 @@ pub struct ValidateResult {
 #[tauri::command]
 pub fn get_github_config(
     state: State<SettingsState>,
-) -> Result<Option<GitHubConfigMeta>, String> {
+) -> Result<Option<GitHubConfigMeta>, GrovrError> {
     let settings = state.0.lock().map_err(|e| e.to_string())?;
     Ok(settings.github_configs.first().cloned())
 }
@@ -52,7 +53,7 @@ pub fn set_github_config(
     app: tauri::AppHandle,
     state: State<SettingsState>,
     config: GitHubConfig,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     // Store token in secure storage
     let token_key = secure_store::github_token_key(&config.id);
     secure_store::store_secret(&token_key, &config.token)?;
@@ -68,7 +69,7 @@ pub fn set_github_config(
 pub fn remove_github_config(
     app: tauri::AppHandle,
     state: State<SettingsState>,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     let mut settings = state.0.lock().map_err(|e| e.to_string())?;
 
     // Delete token from secure storage
@@ -82,7 +83,7 @@ pub fn remove_github_config(
 }
 
 #[tauri::command]
-pub async fn validate_github_token(config: GitHubConfig) -> Result<ValidateResult, String> {
+pub async fn validate_github_token(config: GitHubConfig) -> Result<ValidateResult, GrovrError> {
     let client = Client::new();
 
     let base_url = if config.config_type == "enterprise" {
@@ -97,15 +98,14 @@ pub async fn validate_github_token(config: GitHubConfig) -> Result<ValidateResul
         .header("User-Agent", "Grovr-Desktop")
         .header("Accept", "application/vnd.github+json")
         .send()
-        .await
-        .map_err(|e| e.to_string())?;
+        .await?;
 
     if response.status().is_success() {
         #[derive(Deserialize)]
         struct GitHubUser {
             login: String,
         }
-        let user: GitHubUser = response.json().await.map_err(|e| e.to_string())?;
+        let user: GitHubUser = response.json().await?;
         Ok(ValidateResult {
             valid: true,
             username: Some(user.login),
@@ -132,7 +132,7 @@ pub async fn validate_github_token(config: GitHubConfig) -> Result<ValidateResul
 #[tauri::command]
 pub fn get_jira_config(
     state: State<SettingsState>,
-) -> Result<Option<JiraConfigMeta>, String> {
+) -> Result<Option<JiraConfigMeta>, GrovrError> {
     let settings = state.0.lock().map_err(|e| e.to_string())?;
     let meta = settings.jira_configs.first().cloned();
 
@@ -155,7 +155,7 @@ pub fn set_jira_config(
     app: tauri::AppHandle,
     state: State<SettingsState>,
     config: JiraConfig,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     eprintln!("[Jira] set_jira_config called - host: {}, email: {:?}, has_token: {}",
         config.host, config.email, config.api_token.is_some());
 
@@ -168,7 +168,7 @@ pub fn set_jira_config(
                 Ok(()) => eprintln!("[Jira] Token stored successfully"),
                 Err(e) => {
                     eprintln!("[Jira] Failed to store token: {}", e);
-                    return Err(e);
+                    return Err(e.into());
                 }
             }
         } else {
@@ -189,7 +189,7 @@ pub fn set_jira_config(
 pub fn remove_jira_config(
     app: tauri::AppHandle,
     state: State<SettingsState>,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     let mut settings = state.0.lock().map_err(|e| e.to_string())?;
 
     // Delete token from secure storage
@@ -203,7 +203,7 @@ pub fn remove_jira_config(
 }
 
 #[tauri::command]
-pub async fn validate_jira_credentials(config: JiraConfig) -> Result<ValidateResult, String> {
+pub async fn validate_jira_credentials(config: JiraConfig) -> Result<ValidateResult, GrovrError> {
     let email = config.email.as_ref().filter(|e| !e.is_empty())
         .ok_or("Email is required for validation")?;
     let api_token = config.api_token.as_ref().filter(|t| !t.is_empty())
@@ -219,8 +219,7 @@ pub async fn validate_jira_credentials(config: JiraConfig) -> Result<ValidateRes
         .header("Authorization", format!("Basic {}", auth))
         .header("Accept", "application/json")
         .send()
-        .await
-        .map_err(|e| e.to_string())?;
+        .await?;
 
     if response.status().is_success() {
         #[derive(Deserialize)]
@@ -228,7 +227,7 @@ pub async fn validate_jira_credentials(config: JiraConfig) -> Result<ValidateRes
             #[serde(rename = "displayName")]
             display_name: String,
         }
-        let user: JiraUser = response.json().await.map_err(|e| e.to_string())?;
+        let user: JiraUser = response.json().await?;
         Ok(ValidateResult {
             valid: true,
             username: Some(user.display_name),
@@ -271,37 +270,8 @@ pub async fn fetch_pull_requests(
     owner: String,
     repo: String,
     branch: String,
-) -> Result<Vec<PullRequestInfo>, String> {
-    // Extract config data before await to avoid holding MutexGuard across await
-    let (base_url, token) = {
-        let settings = state.0.lock().map_err(|e| e.to_string())?;
-        let meta = settings.github_configs.first()
-            .ok_or("No GitHub config found")?;
-
-        let base_url = if meta.config_type == "enterprise" {
-            format!("https://{}/api/v3", meta.host.as_deref().unwrap_or("github.com"))
-        } else {
-            "https://api.github.com".to_string()
-        };
-
-        // Get token from secure storage (with migration from old format)
-        let token_key = secure_store::github_token_key(&meta.id);
-        let meta_id = meta.id.clone();
-        migrate_token_if_needed(&app, &token_key, |settings_value| {
-            settings_value
-                .get("github_configs")
-                .and_then(|arr| arr.as_array())
-                .and_then(|arr| arr.iter().find(|c| c.get("id").and_then(|v| v.as_str()) == Some(meta_id.as_str())))
-                .and_then(|c| c.get("token"))
-                .and_then(|v| v.as_str())
-                .map(|s| s.to_string())
-        });
-        let token = secure_store::get_secret(&token_key)?.unwrap_or_default();
-        if token.is_empty() {
-            eprintln!("[GitHub] Warning: No token found in secure storage for key: {}", token_key);
-        }
-        (base_url, token)
-    };
+) -> Result<Vec<PullRequestInfo>, GrovrError> {
+    let (base_url, token) = github_credentials(&app, &state)?;
 
     let url = format!("{}/repos/{}/{}/pulls", base_url, owner, repo);
     let head_filter = format!("{}:{}", owner, branch);
@@ -315,14 +285,10 @@ pub async fn fetch_pull_requests(
         .header("User-Agent", "Grovr-Desktop")
         .header("Accept", "application/vnd.github+json")
         .send()
-        .await
-        .map_err(|e| e.to_string())?;
+        .await?;
 
     if !response.status().is_success() {
-        let status = response.status().as_u16();
-        let body = response.text().await.unwrap_or_default();
-        eprintln!("[GitHub] API error ({}): {}", status, body);
-        return Err(format!("GitHub API error ({}): {}", status, body));
+        return Err(api_error("GitHub", response).await);
     }
 
     #[derive(Deserialize)]
@@ -335,7 +301,7 @@ pub async fn fetch_pull_requests(
         html_url: String,
     }
 
-    let prs: Vec<GitHubPR> = response.json().await.map_err(|e| e.to_string())?;
+    let prs: Vec<GitHubPR> = response.json().await?;
 
     Ok(prs.into_iter().map(|pr| PullRequestInfo {
         number: pr.number,
@@ -349,6 +315,86 @@ pub async fn fetch_pull_requests(
     }).collect())
 }
 
+/// Head of a pull request, enough to check it out locally.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PullRequestHead {
+    pub number: u64,
+    pub title: String,
+    pub state: String,
+    pub url: String,
+    /// Branch name in the head repository
+    pub head_ref: String,
+    pub head_sha: String,
+    /// `owner/repo` of the head, `None` when the fork was deleted
+    pub head_repo: Option<String>,
+    pub head_clone_url: Option<String>,
+    /// Whether the head lives in a different repository than the base
+    pub is_fork: bool,
+}
+
+#[tauri::command]
+pub async fn fetch_pull_request(
+    app: tauri::AppHandle,
+    state: State<'_, SettingsState>,
+    owner: String,
+    repo: String,
+    number: u64,
+) -> Result<PullRequestHead, GrovrError> {
+    let (base_url, token) = github_credentials(&app, &state)?;
+
+    let url = format!("{}/repos/{}/{}/pulls/{}", base_url, owner, repo, number);
+    let client = Client::new();
+    let response = client
+        .get(&url)
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "Grovr-Desktop")
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(api_error("GitHub", response).await);
+    }
+
+    #[derive(Deserialize)]
+    struct GitHubPR {
+        number: u64,
+        title: String,
+        state: String,
+        html_url: String,
+        head: GitHubRef,
+        base: GitHubRef,
+    }
+    #[derive(Deserialize)]
+    struct GitHubRef {
+        #[serde(rename = "ref")]
+        ref_name: String,
+        sha: String,
+        repo: Option<GitHubRepo>,
+    }
+    #[derive(Deserialize)]
+    struct GitHubRepo {
+        full_name: String,
+        clone_url: String,
+    }
+
+    let pr: GitHubPR = response.json().await?;
+    let head_repo = pr.head.repo.as_ref().map(|r| r.full_name.clone());
+    let base_repo = pr.base.repo.as_ref().map(|r| r.full_name.clone());
+
+    Ok(PullRequestHead {
+        number: pr.number,
+        title: pr.title,
+        state: pr.state,
+        url: pr.html_url,
+        is_fork: head_repo.is_none() || head_repo != base_repo,
+        head_ref: pr.head.ref_name,
+        head_sha: pr.head.sha,
+        head_clone_url: pr.head.repo.map(|r| r.clone_url),
+        head_repo,
+    })
+}
+
 // ============ Jira Data Fetching ============
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -365,7 +411,7 @@ pub async fn fetch_jira_issue(
     app: tauri::AppHandle,
     state: State<'_, SettingsState>,
     issue_key: String,
-) -> Result<Option<JiraIssueInfo>, String> {
+) -> Result<Option<JiraIssueInfo>, GrovrError> {
     eprintln!("[Jira] fetch_jira_issue called for: {}", issue_key);
 
     // Extract config data before await to avoid holding MutexGuard across await
@@ -443,16 +489,13 @@ pub async fn fetch_jira_issue(
         .await
         .map_err(|e| {
             eprintln!("[Jira] Request error: {}", e);
-            e.to_string()
+            e
         })?;
 
     eprintln!("[Jira] Response status: {}", response.status());
 
     if !response.status().is_success() {
-        let status = response.status().as_u16();
-        let body = response.text().await.unwrap_or_default();
-        eprintln!("[Jira] API error ({}): {}", status, body);
-        return Err(format!("Jira API error ({}): {}", status, body));
+        return Err(api_error("Jira", response).await);
     }
 
     #[derive(Deserialize)]
@@ -476,7 +519,7 @@ pub async fn fetch_jira_issue(
         key: String,
     }
 
-    let issue: JiraIssue = response.json().await.map_err(|e| e.to_string())?;
+    let issue: JiraIssue = response.json().await?;
 
     Ok(Some(JiraIssueInfo {
         key: issue.key.clone(),
@@ -487,13 +530,71 @@ pub async fn fetch_jira_issue(
     }))
 }
 
+// Helper to read the GitHub API base URL and token. Callers use it before any
+// await so the settings MutexGuard is not held across one.
+fn github_credentials(app: &tauri::AppHandle, state: &SettingsState) -> Result<(String, String), GrovrError> {
+    let settings = state.0.lock().map_err(|e| e.to_string())?;
+    let meta = settings.github_configs.first()
+        .ok_or("No GitHub config found")?;
+
+    let base_url = if meta.config_type == "enterprise" {
+        format!("https://{}/api/v3", meta.host.as_deref().unwrap_or("github.com"))
+    } else {
+        "https://api.github.com".to_string()
+    };
+
+    // Get token from secure storage (with migration from old format)
+    let token_key = secure_store::github_token_key(&meta.id);
+    let meta_id = meta.id.clone();
+    migrate_token_if_needed(app, &token_key, |settings_value| {
+        settings_value
+            .get("github_configs")
+            .and_then(|arr| arr.as_array())
+            .and_then(|arr| arr.iter().find(|c| c.get("id").and_then(|v| v.as_str()) == Some(meta_id.as_str())))
+            .and_then(|c| c.get("token"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    });
+    let token = secure_store::get_secret(&token_key)?.unwrap_or_default();
+    if token.is_empty() {
+        eprintln!("[GitHub] Warning: No token found in secure storage for key: {}", token_key);
+    }
+    Ok((base_url, token))
+}
+
+// Helper to map a failed API response to a typed error
+async fn api_error(service: &str, response: reqwest::Response) -> GrovrError {
+    let status = response.status().as_u16();
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string())
+    };
+
+    // GitHub signals primary rate limits with 403 + x-ratelimit-remaining: 0,
+    // secondary limits and Jira use 429
+    let remaining = header("x-ratelimit-remaining");
+    let reset_at = header("x-ratelimit-reset").and_then(|v| v.parse::<i64>().ok());
+    let rate_limited = status == 429 || (status == 403 && remaining.as_deref() == Some("0"));
+
+    let body = response.text().await.unwrap_or_default();
+    eprintln!("[{}] API error ({}): {}", service, status, body);
+
+    if rate_limited {
+        GrovrError::RateLimited { service: service.to_string(), reset_at }
+    } else if status == 401 {
+        GrovrError::AuthFailed { service: service.to_string() }
+    } else {
+        GrovrError::Api { service: service.to_string(), status, body }
+    }
+}
+
 // Helper to save settings
-fn save_settings(app: &tauri::AppHandle, settings: &crate::types::AppSettings) -> Result<(), String> {
-    let store = app.store(STORE_PATH).map_err(|e| e.to_string())?;
-    store.set(
-        SETTINGS_KEY,
-        serde_json::to_value(settings).map_err(|e| e.to_string())?,
-    );
-    store.save().map_err(|e| e.to_string())?;
+fn save_settings(app: &tauri::AppHandle, settings: &crate::types::AppSettings) -> Result<(), GrovrError> {
+    let store = app.store(STORE_PATH)?;
+    store.set(SETTINGS_KEY, serde_json::to_value(settings)?);
+    store.save()?;
     Ok(())
 }
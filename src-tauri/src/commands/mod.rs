@@ -3,3 +3,5 @@ pub mod settings;
 pub mod projects;
 pub mod git;
 pub mod integrations;
+pub mod hooks;
+pub mod operations;
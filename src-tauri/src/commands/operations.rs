@@ -0,0 +1,345 @@
+use crate::error::GrovrError;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use tauri::{Emitter, Manager, State};
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::process::{Child, Command};
+use tokio::sync::Notify;
+
+/// Finished operations kept for `get_operations`.
+const HISTORY_LIMIT: usize = 20;
+
+/// Running and recently finished long git operations.
+#[derive(Default)]
+pub struct OperationState {
+    next_id: AtomicU64,
+    inner: Mutex<Operations>,
+}
+
+#[derive(Default)]
+struct Operations {
+    cancels: HashMap<u64, Arc<CancelToken>>,
+    history: VecDeque<OperationRecord>,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationKind {
+    Fetch,
+    Pull,
+    CreateWorktree,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationStatus {
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+/// Payload of the `operation-started` and `operation-finished` events.
+#[derive(Debug, Serialize, Clone)]
+pub struct OperationRecord {
+    pub id: u64,
+    pub kind: OperationKind,
+    /// Repository or worktree the operation runs in
+    pub path: String,
+    pub status: OperationStatus,
+    pub error: Option<String>,
+    pub started_at: i64,
+    pub duration_ms: Option<u64>,
+}
+
+/// Payload of the `operation-progress` event: one line of git's progress output.
+#[derive(Debug, Serialize, Clone)]
+pub struct OperationProgress {
+    pub id: u64,
+    pub line: String,
+    /// Percentage of the current phase, when the line reports one
+    pub percent: Option<u8>,
+}
+
+#[derive(Default)]
+struct CancelToken {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl CancelToken {
+    fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        // Stores a permit when nothing is waiting yet
+        self.notify.notify_one();
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    async fn cancelled(&self) {
+        if !self.is_cancelled() {
+            self.notify.notified().await;
+        }
+    }
+}
+
+/// Handle to one tracked operation. Git commands run through it report
+/// progress and stop when the operation is cancelled.
+pub struct Operation {
+    app: Option<tauri::AppHandle>,
+    record: OperationRecord,
+    started: Instant,
+    cancel: Arc<CancelToken>,
+}
+
+impl Operation {
+    /// Registers an operation and emits `operation-started`.
+    pub fn start(app: &tauri::AppHandle, kind: OperationKind, path: &str) -> Operation {
+        let state = app.state::<OperationState>();
+        let record = OperationRecord {
+            id: state.next_id.fetch_add(1, Ordering::SeqCst) + 1,
+            kind,
+            path: path.to_string(),
+            status: OperationStatus::Running,
+            error: None,
+            started_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or_default(),
+            duration_ms: None,
+        };
+        let cancel = Arc::new(CancelToken::default());
+
+        if let Ok(mut ops) = state.inner.lock() {
+            ops.cancels.insert(record.id, cancel.clone());
+            ops.history.push_back(record.clone());
+            while ops.history.len() > HISTORY_LIMIT {
+                ops.history.pop_front();
+            }
+        }
+        let _ = app.emit("operation-started", record.clone());
+
+        Operation { app: Some(app.clone()), record, started: Instant::now(), cancel }
+    }
+
+    /// An operation that reports nowhere and is never cancelled.
+    pub fn untracked(kind: OperationKind, path: &str) -> Operation {
+        Operation {
+            app: None,
+            record: OperationRecord {
+                id: 0,
+                kind,
+                path: path.to_string(),
+                status: OperationStatus::Running,
+                error: None,
+                started_at: 0,
+                duration_ms: None,
+            },
+            started: Instant::now(),
+            cancel: Arc::new(CancelToken::default()),
+        }
+    }
+
+    /// Runs `git` in `dir`, streaming its stderr as progress, and returns stdout.
+    pub async fn git(&self, dir: &str, args: &[&str]) -> Result<String, GrovrError> {
+        let id = self.record.id;
+        run_git_streaming(dir, args, &self.cancel, |line, percent| {
+            if let Some(app) = &self.app {
+                let _ = app.emit("operation-progress", OperationProgress { id, line, percent });
+            }
+        })
+        .await
+    }
+
+    /// Records the outcome in the history and emits `operation-finished`.
+    pub fn finish<T>(mut self, result: Result<T, GrovrError>) -> Result<T, GrovrError> {
+        self.record.duration_ms = Some(self.started.elapsed().as_millis() as u64);
+        self.record.status = match &result {
+            Ok(_) => OperationStatus::Succeeded,
+            Err(GrovrError::Cancelled) => OperationStatus::Cancelled,
+            Err(_) => OperationStatus::Failed,
+        };
+        self.record.error = result.as_ref().err().map(|e| e.to_string());
+
+        if let Some(app) = &self.app {
+            let state = app.state::<OperationState>();
+            if let Ok(mut ops) = state.inner.lock() {
+                ops.cancels.remove(&self.record.id);
+                if let Some(entry) = ops.history.iter_mut().find(|r| r.id == self.record.id) {
+                    *entry = self.record.clone();
+                }
+            }
+            let _ = app.emit("operation-finished", self.record.clone());
+        }
+        result
+    }
+}
+
+/// Cancels a running operation. Returns whether it was still running.
+#[tauri::command]
+pub fn cancel_operation(state: State<OperationState>, id: u64) -> Result<bool, GrovrError> {
+    let ops = state.inner.lock().map_err(|e| e.to_string())?;
+    match ops.cancels.get(&id) {
+        Some(cancel) => {
+            cancel.cancel();
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Running and recent operations, newest first.
+#[tauri::command]
+pub fn get_operations(state: State<OperationState>) -> Result<Vec<OperationRecord>, GrovrError> {
+    let ops = state.inner.lock().map_err(|e| e.to_string())?;
+    Ok(ops.history.iter().rev().cloned().collect())
+}
+
+async fn run_git_streaming(
+    dir: &str,
+    args: &[&str],
+    cancel: &CancelToken,
+    mut on_progress: impl FnMut(String, Option<u8>),
+) -> Result<String, GrovrError> {
+    if cancel.is_cancelled() {
+        return Err(GrovrError::Cancelled);
+    }
+
+    let mut cmd = Command::new("git");
+    cmd.args(args)
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    // Own process group, so cancelling also stops ssh and remote helpers
+    #[cfg(unix)]
+    cmd.process_group(0);
+
+    let mut child = cmd.spawn().map_err(|e| GrovrError::from_spawn(dir, e))?;
+    let stdout = read_all(child.stdout.take());
+    let mut stderr = child.stderr.take();
+
+    // Progress lines are redrawn with `\r`; only lines ending in `\n` are
+    // kept for the error message
+    let mut messages = String::new();
+    let mut line = Vec::new();
+    let mut buf = [0u8; 4096];
+
+    let status = loop {
+        tokio::select! {
+            read = async {
+                match stderr.as_mut() {
+                    Some(stderr) => stderr.read(&mut buf).await,
+                    None => std::future::pending().await,
+                }
+            } => {
+                let n = match read {
+                    Ok(n) if n > 0 => n,
+                    _ => {
+                        stderr = None;
+                        continue;
+                    }
+                };
+                for &byte in &buf[..n] {
+                    if byte != b'\r' && byte != b'\n' {
+                        line.push(byte);
+                        continue;
+                    }
+                    let text = String::from_utf8_lossy(&line).trim().to_string();
+                    line.clear();
+                    if text.is_empty() {
+                        continue;
+                    }
+                    let percent = progress_percent(&text);
+                    if byte == b'\n' && percent.is_none() {
+                        messages.push_str(&text);
+                        messages.push('\n');
+                    }
+                    on_progress(text, percent);
+                }
+            }
+            status = child.wait() => break status.map_err(|e| GrovrError::from_spawn(dir, e))?,
+            _ = cancel.cancelled() => {
+                kill_process_tree(&mut child).await;
+                return Err(GrovrError::Cancelled);
+            }
+        }
+    };
+
+    // Anything git wrote after the last read
+    if let Some(mut stderr) = stderr {
+        let mut rest = Vec::new();
+        let _ = stderr.read_to_end(&mut rest).await;
+        line.extend(rest);
+    }
+    messages.push_str(String::from_utf8_lossy(&line).trim());
+
+    let stdout = stdout.await.unwrap_or_default();
+    if !status.success() {
+        return Err(GrovrError::from_git_stderr(dir, &messages));
+    }
+    Ok(String::from_utf8_lossy(&stdout).to_string())
+}
+
+fn read_all(reader: Option<impl AsyncRead + Unpin + Send + 'static>) -> tokio::task::JoinHandle<Vec<u8>> {
+    tokio::spawn(async move {
+        let mut out = Vec::new();
+        if let Some(mut reader) = reader {
+            let _ = reader.read_to_end(&mut out).await;
+        }
+        out
+    })
+}
+
+/// Reads the percentage from progress lines like `Receiving objects:  45% (450/1000)`.
+fn progress_percent(line: &str) -> Option<u8> {
+    let end = line.find('%')?;
+    let start = line[..end].rfind(|c: char| !c.is_ascii_digit()).map_or(0, |i| i + 1);
+    line[start..end].parse::<u8>().ok().filter(|p| *p <= 100)
+}
+
+/// Kills a child started in its own process group, along with its descendants.
+pub(crate) async fn kill_process_tree(child: &mut Child) {
+    #[cfg(unix)]
+    if let Some(pid) = child.id() {
+        let _ = Command::new("kill").args(["-KILL", &format!("-{}", pid)]).status().await;
+    }
+    let _ = child.kill().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_percent() {
+        assert_eq!(progress_percent("Receiving objects:  45% (450/1000), 1.2 MiB | 3 MiB/s"), Some(45));
+        assert_eq!(progress_percent("Resolving deltas: 100% (12/12), done."), Some(100));
+        assert_eq!(progress_percent("From github.com:acme/app"), None);
+        assert_eq!(progress_percent("50 %"), None);
+    }
+
+    #[tokio::test]
+    async fn test_run_git_streaming_errors_and_cancel() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().to_string_lossy().to_string();
+
+        let out = run_git_streaming(&path, &["--version"], &CancelToken::default(), |_, _| {}).await.unwrap();
+        assert!(out.starts_with("git version"));
+
+        let err = run_git_streaming(&path, &["status"], &CancelToken::default(), |_, _| {}).await.unwrap_err();
+        assert!(matches!(err, GrovrError::NotAGitRepo { .. }));
+
+        let cancel = CancelToken::default();
+        cancel.cancel();
+        let err = run_git_streaming(&path, &["--version"], &cancel, |_, _| {}).await.unwrap_err();
+        assert!(matches!(err, GrovrError::Cancelled));
+    }
+}
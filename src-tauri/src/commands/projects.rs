@@ -1,5 +1,9 @@
 use crate::commands::settings::SettingsState;
-use crate::types::ProjectConfig;
+use crate::error::GrovrError;
+use crate::repo_config::{self, RepoConfig};
+use crate::types::{ProjectConfig, ProjectSettings};
+use crate::watcher;
+use serde::Serialize;
 use tauri::State;
 use tauri_plugin_store::StoreExt;
 
@@ -9,21 +13,54 @@ const SETTINGS_KEY: &str = "settings";
 fn save_settings_internal(
     app: &tauri::AppHandle,
     state: &SettingsState,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     let settings = state.0.lock().map_err(|e| e.to_string())?;
-    let store = app.store(STORE_PATH).map_err(|e| e.to_string())?;
-    store.set(
-        SETTINGS_KEY,
-        serde_json::to_value(&*settings).map_err(|e| e.to_string())?,
-    );
-    store.save().map_err(|e| e.to_string())?;
+    let store = app.store(STORE_PATH)?;
+    store.set(SETTINGS_KEY, serde_json::to_value(&*settings)?);
+    store.save()?;
     Ok(())
 }
 
+/// A project's local settings and its repository's `.grovr.toml`, kept apart for editing.
+#[derive(Debug, Serialize)]
+pub struct ProjectConfigSources {
+    pub local: ProjectConfig,
+    pub repo: Option<RepoConfig>,
+}
+
+/// Projects with their `.grovr.toml` merged under the local settings.
+#[tauri::command]
+pub fn get_projects(state: State<SettingsState>) -> Result<Vec<ProjectConfig>, GrovrError> {
+    let settings = state.0.lock().map_err(|e| e.to_string())?;
+    Ok(settings.projects.iter().map(repo_config::merged).collect())
+}
+
+#[tauri::command]
+pub fn get_project_config(
+    state: State<SettingsState>,
+    repo_path: String,
+) -> Result<ProjectConfigSources, GrovrError> {
+    let local = {
+        let settings = state.0.lock().map_err(|e| e.to_string())?;
+        settings
+            .projects
+            .iter()
+            .find(|p| p.repo_path == repo_path)
+            .cloned()
+            .ok_or("Project not found")?
+    };
+    let repo = repo_config::load(&repo_path)?;
+    Ok(ProjectConfigSources { local, repo })
+}
+
+/// Effective worktree settings of a project, with its overrides applied over the global ones.
 #[tauri::command]
-pub fn get_projects(state: State<SettingsState>) -> Result<Vec<ProjectConfig>, String> {
+pub fn get_project_settings(
+    state: State<SettingsState>,
+    repo_path: String,
+) -> Result<ProjectSettings, GrovrError> {
     let settings = state.0.lock().map_err(|e| e.to_string())?;
-    Ok(settings.projects.clone())
+    Ok(settings.project_settings(&repo_path))
 }
 
 #[tauri::command]
@@ -31,18 +68,25 @@ pub fn add_project(
     app: tauri::AppHandle,
     state: State<SettingsState>,
     project: ProjectConfig,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
+    // Surface a broken .grovr.toml now; later reads silently skip it
+    repo_config::load(&project.repo_path)?;
+
     {
         let mut settings = state.0.lock().map_err(|e| e.to_string())?;
 
         // Check if project with same path already exists
         if settings.projects.iter().any(|p| p.repo_path == project.repo_path) {
-            return Err("Project with this path already exists".to_string());
+            return Err(GrovrError::PathExists { path: project.repo_path });
         }
 
-        settings.projects.push(project);
+        settings.projects.push(project.clone());
     }
-    save_settings_internal(&app, &state)
+    save_settings_internal(&app, &state)?;
+
+    // Watching is best-effort; the list still refreshes on its timer without it
+    let _ = watcher::watch_project(&app, &project.repo_path);
+    Ok(())
 }
 
 #[tauri::command]
@@ -51,16 +95,20 @@ pub fn update_project(
     state: State<SettingsState>,
     repo_path: String,
     project: ProjectConfig,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     {
         let mut settings = state.0.lock().map_err(|e| e.to_string())?;
 
         if let Some(idx) = settings.projects.iter().position(|p| p.repo_path == repo_path) {
-            settings.projects[idx] = project;
+            settings.projects[idx] = project.clone();
         } else {
-            return Err("Project not found".to_string());
+            return Err("Project not found".into());
         }
     }
+    if project.repo_path != repo_path {
+        watcher::unwatch_project(&app, &repo_path);
+        let _ = watcher::watch_project(&app, &project.repo_path);
+    }
     save_settings_internal(&app, &state)
 }
 
@@ -69,11 +117,12 @@ pub fn remove_project(
     app: tauri::AppHandle,
     state: State<SettingsState>,
     repo_path: String,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     {
         let mut settings = state.0.lock().map_err(|e| e.to_string())?;
         settings.projects.retain(|p| p.repo_path != repo_path);
     }
+    watcher::unwatch_project(&app, &repo_path);
     save_settings_internal(&app, &state)
 }
 
@@ -82,7 +131,7 @@ pub fn reorder_projects(
     app: tauri::AppHandle,
     state: State<SettingsState>,
     repo_paths: Vec<String>,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     {
         let mut settings = state.0.lock().map_err(|e| e.to_string())?;
 
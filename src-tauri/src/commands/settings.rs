@@ -1,4 +1,6 @@
-use crate::types::{AppSettings, IdeConfig, WorktreeMemo};
+use crate::error::GrovrError;
+use crate::repo_config;
+use crate::types::{AppSettings, CopyPathSpec, IdeConfig, WorktreeMemo};
 use tauri::{Manager, State};
 #[cfg(not(target_os = "macos"))]
 use tauri_plugin_autostart::ManagerExt;
@@ -19,13 +21,10 @@ fn load_settings(app: &tauri::AppHandle) -> AppSettings {
         .unwrap_or_default()
 }
 
-fn save_settings(app: &tauri::AppHandle, settings: &AppSettings) -> Result<(), String> {
-    let store = app.store(STORE_PATH).map_err(|e| e.to_string())?;
-    store.set(
-        SETTINGS_KEY,
-        serde_json::to_value(settings).map_err(|e| e.to_string())?,
-    );
-    store.save().map_err(|e| e.to_string())?;
+pub(crate) fn save_settings(app: &tauri::AppHandle, settings: &AppSettings) -> Result<(), GrovrError> {
+    let store = app.store(STORE_PATH)?;
+    store.set(SETTINGS_KEY, serde_json::to_value(settings)?);
+    store.save()?;
     Ok(())
 }
 
@@ -34,9 +33,11 @@ pub fn init_settings(app: &tauri::AppHandle) -> SettingsState {
 }
 
 #[tauri::command]
-pub fn get_settings(state: State<SettingsState>) -> Result<AppSettings, String> {
+pub fn get_settings(state: State<SettingsState>) -> Result<AppSettings, GrovrError> {
     let settings = state.0.lock().map_err(|e| e.to_string())?;
-    Ok(settings.clone())
+    let mut settings = settings.clone();
+    settings.projects = settings.projects.iter().map(repo_config::merged).collect();
+    Ok(settings)
 }
 
 #[tauri::command]
@@ -44,7 +45,7 @@ pub fn set_ide(
     app: tauri::AppHandle,
     state: State<SettingsState>,
     ide: IdeConfig,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     let mut settings = state.0.lock().map_err(|e| e.to_string())?;
     settings.ide = Some(ide);
     save_settings(&app, &settings)
@@ -55,7 +56,7 @@ pub fn set_theme(
     app: tauri::AppHandle,
     state: State<SettingsState>,
     theme: String,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     let mut settings = state.0.lock().map_err(|e| e.to_string())?;
     settings.theme = theme;
     save_settings(&app, &settings)
@@ -66,7 +67,7 @@ pub fn set_launch_at_startup(
     app: tauri::AppHandle,
     state: State<SettingsState>,
     enabled: bool,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     // macOS: Use SMAppService for native login item management
     #[cfg(target_os = "macos")]
     {
@@ -101,7 +102,7 @@ pub fn set_default_worktree_template(
     app: tauri::AppHandle,
     state: State<SettingsState>,
     template: String,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     let mut settings = state.0.lock().map_err(|e| e.to_string())?;
     settings.default_worktree_template = Some(template);
     save_settings(&app, &settings)
@@ -111,8 +112,8 @@ pub fn set_default_worktree_template(
 pub fn set_copy_paths(
     app: tauri::AppHandle,
     state: State<SettingsState>,
-    paths: Vec<String>,
-) -> Result<(), String> {
+    paths: Vec<CopyPathSpec>,
+) -> Result<(), GrovrError> {
     let mut settings = state.0.lock().map_err(|e| e.to_string())?;
     settings.copy_paths = Some(paths);
     save_settings(&app, &settings)
@@ -123,7 +124,7 @@ pub fn set_fetch_before_create(
     app: tauri::AppHandle,
     state: State<SettingsState>,
     enabled: bool,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     let mut settings = state.0.lock().map_err(|e| e.to_string())?;
     settings.fetch_before_create = Some(enabled);
     save_settings(&app, &settings)
@@ -134,7 +135,7 @@ pub fn set_clipboard_parse_patterns(
     app: tauri::AppHandle,
     state: State<SettingsState>,
     patterns: Vec<String>,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     let mut settings = state.0.lock().map_err(|e| e.to_string())?;
     settings.clipboard_parse_patterns = Some(patterns);
     save_settings(&app, &settings)
@@ -145,7 +146,7 @@ pub fn set_last_used_project(
     app: tauri::AppHandle,
     state: State<SettingsState>,
     project: String,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     let mut settings = state.0.lock().map_err(|e| e.to_string())?;
     settings.last_used_project = Some(project);
     save_settings(&app, &settings)
@@ -156,7 +157,7 @@ pub fn set_refresh_interval_minutes(
     app: tauri::AppHandle,
     state: State<SettingsState>,
     minutes: i32,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     let mut settings = state.0.lock().map_err(|e| e.to_string())?;
     settings.refresh_interval_minutes = minutes;
     save_settings(&app, &settings)
@@ -167,7 +168,7 @@ pub fn set_skip_open_ide_confirm(
     app: tauri::AppHandle,
     state: State<SettingsState>,
     skip: bool,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     let mut settings = state.0.lock().map_err(|e| e.to_string())?;
     settings.skip_open_ide_confirm = Some(skip);
     save_settings(&app, &settings)
@@ -178,7 +179,7 @@ pub fn set_onboarding_completed(
     app: tauri::AppHandle,
     state: State<SettingsState>,
     completed: bool,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     let mut settings = state.0.lock().map_err(|e| e.to_string())?;
     settings.onboarding_completed = Some(completed);
     save_settings(&app, &settings)
@@ -188,7 +189,7 @@ pub fn set_onboarding_completed(
 pub fn get_worktree_memo(
     state: State<SettingsState>,
     path: String,
-) -> Result<WorktreeMemo, String> {
+) -> Result<WorktreeMemo, GrovrError> {
     let settings = state.0.lock().map_err(|e| e.to_string())?;
     Ok(settings.worktree_memos.get(&path).cloned().unwrap_or_default())
 }
@@ -199,7 +200,7 @@ pub fn set_worktree_memo(
     state: State<SettingsState>,
     path: String,
     memo: WorktreeMemo,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     let mut settings = state.0.lock().map_err(|e| e.to_string())?;
     settings.worktree_memos.insert(path, memo);
     save_settings(&app, &settings)
@@ -237,7 +238,7 @@ pub fn set_global_shortcut(
     app: tauri::AppHandle,
     state: State<SettingsState>,
     shortcut: Option<String>,
-) -> Result<(), String> {
+) -> Result<(), GrovrError> {
     // 1. Unregister existing shortcut
     let old_shortcut = {
         let settings = state.0.lock().map_err(|e| e.to_string())?;
@@ -0,0 +1,269 @@
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::path::Path;
+
+/// Error returned by every Tauri command.
+///
+/// Serialized as `{ kind, message, ...fields }` so the frontend can branch on
+/// `kind` (e.g. offer "force delete?" for `branch_not_merged`) while still
+/// having a human-readable `message` to display.
+#[derive(Debug, thiserror::Error)]
+pub enum GrovrError {
+    #[error("Git is not installed or not found in PATH")]
+    GitNotInstalled,
+
+    #[error("Not a git repository: {path}")]
+    NotAGitRepo { path: String },
+
+    #[error("'{path}' contains modified or untracked files")]
+    DirtyWorktree { path: String },
+
+    #[error("The branch '{branch}' is not fully merged")]
+    BranchNotMerged { branch: String },
+
+    #[error("A branch named '{branch}' already exists")]
+    BranchExists { branch: String },
+
+    #[error("'{path}' already exists")]
+    PathExists { path: String },
+
+    #[error("Worktree '{path}' is locked{}", reason.as_ref().map(|r| format!(": {}", r)).unwrap_or_default())]
+    WorktreeLocked { path: String, reason: Option<String> },
+
+    #[error("Removing '{path}' would lose unpushed work; review removal plan {plan_id} first")]
+    RemovalNotAcknowledged { path: String, plan_id: String },
+
+    #[error("{service} authentication failed")]
+    AuthFailed { service: String },
+
+    #[error("{service} rate limit exceeded")]
+    RateLimited { service: String, reset_at: Option<i64> },
+
+    #[error("{service} API error ({status}): {body}")]
+    Api { service: String, status: u16, body: String },
+
+    #[error("Timed out after {seconds}s")]
+    Timeout { seconds: u64 },
+
+    #[error("Operation was cancelled")]
+    Cancelled,
+
+    #[error("{message}")]
+    Git { message: String },
+
+    #[error("{0}")]
+    Other(String),
+}
+
+impl GrovrError {
+    /// Stable identifier the frontend matches on.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            GrovrError::GitNotInstalled => "git_not_installed",
+            GrovrError::NotAGitRepo { .. } => "not_a_git_repo",
+            GrovrError::DirtyWorktree { .. } => "dirty_worktree",
+            GrovrError::BranchNotMerged { .. } => "branch_not_merged",
+            GrovrError::BranchExists { .. } => "branch_exists",
+            GrovrError::PathExists { .. } => "path_exists",
+            GrovrError::WorktreeLocked { .. } => "worktree_locked",
+            GrovrError::RemovalNotAcknowledged { .. } => "removal_not_acknowledged",
+            GrovrError::AuthFailed { .. } => "auth_failed",
+            GrovrError::RateLimited { .. } => "rate_limited",
+            GrovrError::Api { .. } => "api",
+            GrovrError::Timeout { .. } => "timeout",
+            GrovrError::Cancelled => "cancelled",
+            GrovrError::Git { .. } => "git",
+            GrovrError::Other(_) => "other",
+        }
+    }
+
+    /// Maps a failure to spawn `git` in `dir`.
+    ///
+    /// `Command` reports a missing working directory with the same `NotFound`
+    /// error as a missing binary, so the directory is checked first.
+    pub fn from_spawn(dir: &str, err: std::io::Error) -> Self {
+        if !Path::new(dir).exists() {
+            return GrovrError::NotAGitRepo { path: dir.to_string() };
+        }
+        if err.kind() == std::io::ErrorKind::NotFound {
+            return GrovrError::GitNotInstalled;
+        }
+        GrovrError::Other(format!("Failed to run git: {}", err))
+    }
+
+    /// Classifies git's stderr into a typed error, falling back to `Git`.
+    pub fn from_git_stderr(dir: &str, stderr: &str) -> Self {
+        let stderr = stderr.trim();
+
+        if stderr.contains("not a git repository") {
+            return GrovrError::NotAGitRepo { path: dir.to_string() };
+        }
+        if stderr.contains("contains modified or untracked files") {
+            let path = first_quoted(stderr).unwrap_or(dir);
+            return GrovrError::DirtyWorktree { path: path.to_string() };
+        }
+        if stderr.contains("is not fully merged")
+            && let Some(branch) = first_quoted(stderr)
+        {
+            return GrovrError::BranchNotMerged { branch: branch.to_string() };
+        }
+        if stderr.contains("a branch named")
+            && stderr.contains("already exists")
+            && let Some(branch) = first_quoted(stderr)
+        {
+            return GrovrError::BranchExists { branch: branch.to_string() };
+        }
+        if stderr.contains("already exists")
+            && let Some(path) = first_quoted(stderr)
+        {
+            return GrovrError::PathExists { path: path.to_string() };
+        }
+
+        GrovrError::Git { message: stderr.to_string() }
+    }
+}
+
+/// Returns the first `'single-quoted'` fragment of a git message.
+fn first_quoted(message: &str) -> Option<&str> {
+    let start = message.find('\'')? + 1;
+    let len = message[start..].find('\'')?;
+    Some(&message[start..start + len])
+}
+
+impl Serialize for GrovrError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("kind", self.kind())?;
+        map.serialize_entry("message", &self.to_string())?;
+
+        match self {
+            GrovrError::NotAGitRepo { path }
+            | GrovrError::DirtyWorktree { path }
+            | GrovrError::PathExists { path } => {
+                map.serialize_entry("path", path)?;
+            }
+            GrovrError::WorktreeLocked { path, reason } => {
+                map.serialize_entry("path", path)?;
+                map.serialize_entry("reason", reason)?;
+            }
+            GrovrError::RemovalNotAcknowledged { path, plan_id } => {
+                map.serialize_entry("path", path)?;
+                map.serialize_entry("plan_id", plan_id)?;
+            }
+            GrovrError::BranchNotMerged { branch } | GrovrError::BranchExists { branch } => {
+                map.serialize_entry("branch", branch)?;
+            }
+            GrovrError::AuthFailed { service } => {
+                map.serialize_entry("service", service)?;
+            }
+            GrovrError::RateLimited { service, reset_at } => {
+                map.serialize_entry("service", service)?;
+                map.serialize_entry("reset_at", reset_at)?;
+            }
+            GrovrError::Api { service, status, .. } => {
+                map.serialize_entry("service", service)?;
+                map.serialize_entry("status", status)?;
+            }
+            GrovrError::Timeout { seconds } => {
+                map.serialize_entry("seconds", seconds)?;
+            }
+            GrovrError::GitNotInstalled
+            | GrovrError::Cancelled
+            | GrovrError::Git { .. }
+            | GrovrError::Other(_) => {}
+        }
+
+        map.end()
+    }
+}
+
+impl From<String> for GrovrError {
+    fn from(message: String) -> Self {
+        GrovrError::Other(message)
+    }
+}
+
+impl From<&str> for GrovrError {
+    fn from(message: &str) -> Self {
+        GrovrError::Other(message.to_string())
+    }
+}
+
+impl From<git2::Error> for GrovrError {
+    fn from(err: git2::Error) -> Self {
+        GrovrError::Git { message: err.message().to_string() }
+    }
+}
+
+impl From<std::io::Error> for GrovrError {
+    fn from(err: std::io::Error) -> Self {
+        GrovrError::Other(err.to_string())
+    }
+}
+
+impl From<reqwest::Error> for GrovrError {
+    fn from(err: reqwest::Error) -> Self {
+        GrovrError::Other(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for GrovrError {
+    fn from(err: serde_json::Error) -> Self {
+        GrovrError::Other(err.to_string())
+    }
+}
+
+impl From<tauri_plugin_store::Error> for GrovrError {
+    fn from(err: tauri_plugin_store::Error) -> Self {
+        GrovrError::Other(err.to_string())
+    }
+}
+
+impl From<tokio::task::JoinError> for GrovrError {
+    fn from(err: tokio::task::JoinError) -> Self {
+        GrovrError::Other(format!("Task failed: {}", err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classifies_dirty_worktree() {
+        let err = GrovrError::from_git_stderr(
+            "/repo",
+            "fatal: '/tmp/wt' contains modified or untracked files, use --force to delete it\n",
+        );
+        assert!(matches!(err, GrovrError::DirtyWorktree { ref path } if path == "/tmp/wt"));
+    }
+
+    #[test]
+    fn test_classifies_branch_errors() {
+        let err = GrovrError::from_git_stderr("/repo", "error: The branch 'feature' is not fully merged.");
+        assert!(matches!(err, GrovrError::BranchNotMerged { ref branch } if branch == "feature"));
+
+        let err = GrovrError::from_git_stderr("/repo", "fatal: a branch named 'feature' already exists");
+        assert!(matches!(err, GrovrError::BranchExists { ref branch } if branch == "feature"));
+    }
+
+    #[test]
+    fn test_classifies_path_exists() {
+        let err = GrovrError::from_git_stderr("/repo", "fatal: '/tmp/wt' already exists");
+        assert!(matches!(err, GrovrError::PathExists { ref path } if path == "/tmp/wt"));
+    }
+
+    #[test]
+    fn test_unknown_stderr_falls_back_to_git() {
+        let err = GrovrError::from_git_stderr("/repo", "fatal: something unexpected\n");
+        assert!(matches!(err, GrovrError::Git { ref message } if message == "fatal: something unexpected"));
+    }
+
+    #[test]
+    fn test_serializes_kind_message_and_fields() {
+        let err = GrovrError::BranchNotMerged { branch: "feature".to_string() };
+        let value = serde_json::to_value(&err).unwrap();
+        assert_eq!(value["kind"], "branch_not_merged");
+        assert_eq!(value["message"], "The branch 'feature' is not fully merged");
+        assert_eq!(value["branch"], "feature");
+    }
+}
@@ -7,8 +7,11 @@ use window_vibrancy::{apply_vibrancy, NSVisualEffectMaterial};
 use window_vibrancy::apply_mica;
 
 mod commands;
+mod error;
+mod repo_config;
 mod secure_store;
 mod types;
+mod watcher;
 
 use commands::settings::{
     get_settings, get_worktree_memo, init_settings, register_global_shortcut,
@@ -17,18 +20,27 @@ use commands::settings::{
     set_launch_at_startup, set_onboarding_completed, set_refresh_interval_minutes,
     set_skip_open_ide_confirm, set_theme, set_worktree_memo,
 };
-use commands::projects::{add_project, get_projects, remove_project, reorder_projects, update_project};
+use commands::projects::{
+    add_project, get_project_config, get_project_settings, get_projects, remove_project,
+    reorder_projects, update_project,
+};
 use commands::git::{
-    get_worktrees, create_worktree, create_worktree_existing_branch, remove_worktree,
-    prune_worktrees, get_worktree_status, get_branches, get_current_branch, get_default_branch,
-    delete_branch, rename_branch, git_fetch, git_pull, get_github_remote_info, open_ide,
-    open_in_finder, open_terminal, copy_paths_to_worktree,
+    get_worktrees, create_worktree, create_worktree_existing_branch, create_worktree_from_pr,
+    remove_worktree, plan_remove_worktree, prune_worktrees, lock_worktree, unlock_worktree,
+    move_worktree, relocate_worktrees, resolve_worktree_path, get_worktree_status,
+    get_project_status, find_stale_worktrees, cleanup_worktrees, archive_worktree,
+    get_archived_worktrees, restore_archived_worktree, delete_archived_worktree, get_branches,
+    get_current_branch, get_default_branch, delete_branch, rename_branch, git_fetch,
+    fetch_base_branch, git_pull, get_github_remote_info, open_ide, open_in_finder,
+    open_terminal, copy_paths_to_worktree,
 };
+use commands::hooks::{cancel_worktree_hooks, run_worktree_hooks, HookState};
+use commands::operations::{cancel_operation, get_operations, OperationState};
 use commands::clipboard::read_clipboard_text;
 use commands::integrations::{
     get_github_config, set_github_config, remove_github_config, validate_github_token,
     get_jira_config, set_jira_config, remove_jira_config, validate_jira_credentials,
-    fetch_pull_requests, fetch_jira_issue,
+    fetch_pull_requests, fetch_pull_request, fetch_jira_issue,
 };
 
 fn setup_window_effects(app: &tauri::App) -> Result<(), Box<dyn std::error::Error>> {
@@ -93,7 +105,20 @@ pub fn run() {
                 }
             }
 
+            // Watch configured projects so external worktree changes reach the UI
+            let repo_paths: Vec<String> = settings_state
+                .0
+                .lock()
+                .map(|s| s.projects.iter().map(|p| p.repo_path.clone()).collect())
+                .unwrap_or_default();
+
             app.manage(settings_state);
+            app.manage(watcher::WatcherState::default());
+            app.manage(HookState::default());
+            app.manage(OperationState::default());
+            for repo_path in &repo_paths {
+                let _ = watcher::watch_project(app.handle(), repo_path);
+            }
 
             // Apply window effects
             setup_window_effects(app)?;
@@ -119,6 +144,8 @@ pub fn run() {
             set_global_shortcut,
             // Projects
             get_projects,
+            get_project_config,
+            get_project_settings,
             add_project,
             update_project,
             remove_project,
@@ -127,9 +154,23 @@ pub fn run() {
             get_worktrees,
             create_worktree,
             create_worktree_existing_branch,
+            create_worktree_from_pr,
             remove_worktree,
+            plan_remove_worktree,
             prune_worktrees,
+            lock_worktree,
+            unlock_worktree,
+            move_worktree,
+            relocate_worktrees,
+            resolve_worktree_path,
             get_worktree_status,
+            get_project_status,
+            find_stale_worktrees,
+            cleanup_worktrees,
+            archive_worktree,
+            get_archived_worktrees,
+            restore_archived_worktree,
+            delete_archived_worktree,
             // Git - Branches
             get_branches,
             get_current_branch,
@@ -138,7 +179,10 @@ pub fn run() {
             rename_branch,
             // Git - Operations
             git_fetch,
+            fetch_base_branch,
             git_pull,
+            cancel_operation,
+            get_operations,
             // Git - Remote
             get_github_remote_info,
             // IDE/File
@@ -146,12 +190,16 @@ pub fn run() {
             open_in_finder,
             open_terminal,
             copy_paths_to_worktree,
+            // Hooks
+            run_worktree_hooks,
+            cancel_worktree_hooks,
             // Integrations - GitHub
             get_github_config,
             set_github_config,
             remove_github_config,
             validate_github_token,
             fetch_pull_requests,
+            fetch_pull_request,
             // Integrations - Jira
             get_jira_config,
             set_jira_config,
@@ -0,0 +1,125 @@
+use crate::error::GrovrError;
+use crate::types::{CopyPathSpec, IdeConfig, ProjectConfig, ProjectHooks};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// File at the repository root that a team commits to share its worktree setup.
+pub const REPO_CONFIG_FILE: &str = ".grovr.toml";
+
+/// Project settings declared in `.grovr.toml`.
+///
+/// Every field is optional. Values sit between the user's local project
+/// overrides and the global settings.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct RepoConfig {
+    pub default_base_branch: Option<String>,
+    /// IDE preset id, e.g. `"cursor"`
+    pub ide: Option<String>,
+    pub worktree_template: Option<String>,
+    pub copy_paths: Option<Vec<CopyPathSpec>>,
+    pub fetch_before_create: Option<bool>,
+    pub clipboard_parse_patterns: Option<Vec<String>>,
+    pub hooks: Option<ProjectHooks>,
+}
+
+/// Reads the repository's `.grovr.toml`. A missing file is `Ok(None)`.
+pub fn load(repo_path: &str) -> Result<Option<RepoConfig>, GrovrError> {
+    let path = Path::new(repo_path).join(REPO_CONFIG_FILE);
+    let content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    toml::from_str(&content)
+        .map(Some)
+        .map_err(|e| GrovrError::Other(format!("Invalid {}: {}", path.display(), e.message())))
+}
+
+/// The project with its `.grovr.toml` filled in under the local values.
+/// An unreadable file is ignored here; `add_project` reports it.
+pub fn merged(project: &ProjectConfig) -> ProjectConfig {
+    match load(&project.repo_path) {
+        Ok(Some(repo)) => merge(project, repo),
+        _ => project.clone(),
+    }
+}
+
+fn merge(local: &ProjectConfig, repo: RepoConfig) -> ProjectConfig {
+    let non_empty = |s: &Option<String>| s.clone().filter(|s| !s.trim().is_empty());
+
+    ProjectConfig {
+        default_base_branch: non_empty(&local.default_base_branch).or(repo.default_base_branch),
+        ide: local.ide.clone().or_else(|| {
+            repo.ide.map(|preset| IdeConfig {
+                ide_type: "preset".to_string(),
+                preset: Some(preset),
+                custom_command: None,
+            })
+        }),
+        worktree_template: non_empty(&local.worktree_template).or(repo.worktree_template),
+        copy_paths: local.copy_paths.clone().or(repo.copy_paths),
+        fetch_before_create: local.fetch_before_create.or(repo.fetch_before_create),
+        clipboard_parse_patterns: local
+            .clipboard_parse_patterns
+            .clone()
+            .filter(|p| !p.is_empty())
+            .or(repo.clipboard_parse_patterns),
+        hooks: local.hooks.clone().or(repo.hooks),
+        ..local.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CopyMode;
+
+    #[test]
+    fn test_merge_keeps_local_overrides() {
+        let repo: RepoConfig = toml::from_str(
+            r#"
+            default_base_branch = "origin/develop"
+            ide = "cursor"
+            worktree_template = "{repo_parent}/wt/{branch}"
+            copy_paths = [".env", { pattern = "node_modules", mode = "symlink" }]
+
+            [hooks]
+            post_create = ["pnpm install"]
+            "#,
+        )
+        .unwrap();
+
+        let local = ProjectConfig {
+            name: "web".to_string(),
+            repo_path: "/src/web".to_string(),
+            default_base_branch: Some("origin/main".to_string()),
+            ide: None,
+            emoji: None,
+            worktree_template: Some("".to_string()),
+            copy_paths: None,
+            fetch_before_create: Some(false),
+            clipboard_parse_patterns: None,
+            hooks: None,
+        };
+
+        let project = merge(&local, repo);
+        assert_eq!(project.default_base_branch.as_deref(), Some("origin/main"));
+        assert_eq!(project.ide.and_then(|ide| ide.preset).as_deref(), Some("cursor"));
+        assert_eq!(project.worktree_template.as_deref(), Some("{repo_parent}/wt/{branch}"));
+        let copy_paths = project.copy_paths.unwrap();
+        assert_eq!(copy_paths[1].mode(), CopyMode::Symlink);
+        assert_eq!(project.fetch_before_create, Some(false));
+        assert_eq!(project.hooks.unwrap().post_create, vec!["pnpm install"]);
+        assert_eq!(project.name, "web");
+    }
+
+    #[test]
+    fn test_load_missing_and_invalid() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let repo_path = dir.path().to_string_lossy().to_string();
+        assert_eq!(load(&repo_path).unwrap(), None);
+
+        std::fs::write(dir.path().join(REPO_CONFIG_FILE), "copy_paths = 3").unwrap();
+        assert!(load(&repo_path).is_err());
+    }
+}
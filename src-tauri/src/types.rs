@@ -16,6 +16,89 @@ pub struct ProjectConfig {
     pub default_base_branch: Option<String>,
     pub ide: Option<IdeConfig>,
     pub emoji: Option<String>,
+    // Overrides of the global worktree settings, `None` falls back to them
+    pub worktree_template: Option<String>,
+    pub copy_paths: Option<Vec<CopyPathSpec>>,
+    pub fetch_before_create: Option<bool>,
+    pub clipboard_parse_patterns: Option<Vec<String>>,
+    pub hooks: Option<ProjectHooks>,
+}
+
+/// Shell commands run in a worktree around its lifecycle, in order.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ProjectHooks {
+    /// Run in the new worktree once it is created and paths are copied
+    #[serde(default)]
+    pub post_create: Vec<String>,
+    /// Run in the worktree before it is removed
+    #[serde(default)]
+    pub pre_remove: Vec<String>,
+    /// Per-command limit, `DEFAULT_HOOK_TIMEOUT_SECS` when unset
+    pub timeout_secs: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CopyMode {
+    #[default]
+    Copy,
+    Symlink,
+    Hardlink,
+    /// Copy-on-write clone, falling back to a plain copy where unsupported
+    Reflink,
+}
+
+/// One `copy_paths` entry: a path or glob relative to the main worktree.
+///
+/// Plain strings (the original format) copy the match. A pattern starting with
+/// `!` excludes matches from every other entry.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum CopyPathSpec {
+    Pattern(String),
+    Entry {
+        pattern: String,
+        #[serde(default)]
+        mode: CopyMode,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        exclude: Vec<String>,
+    },
+}
+
+impl CopyPathSpec {
+    pub fn pattern(&self) -> &str {
+        match self {
+            CopyPathSpec::Pattern(pattern) | CopyPathSpec::Entry { pattern, .. } => pattern,
+        }
+    }
+
+    pub fn mode(&self) -> CopyMode {
+        match self {
+            CopyPathSpec::Pattern(_) => CopyMode::Copy,
+            CopyPathSpec::Entry { mode, .. } => *mode,
+        }
+    }
+
+    pub fn exclude(&self) -> &[String] {
+        match self {
+            CopyPathSpec::Pattern(_) => &[],
+            CopyPathSpec::Entry { exclude, .. } => exclude,
+        }
+    }
+}
+
+pub const DEFAULT_WORKTREE_TEMPLATE: &str = "{project}.worktrees/{branch}-{description}";
+pub const DEFAULT_CLIPBOARD_PATTERN: &str = r"\[(?<issueNumber>[A-Z]+-\d+)\]\s*(?<description>.+)";
+pub const DEFAULT_HOOK_TIMEOUT_SECS: u64 = 600;
+
+/// Effective worktree settings of one project, see `AppSettings::project_settings`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProjectSettings {
+    pub worktree_template: String,
+    pub copy_paths: Vec<CopyPathSpec>,
+    pub fetch_before_create: bool,
+    pub clipboard_parse_patterns: Vec<String>,
+    pub hooks: ProjectHooks,
 }
 
 // Full config sent from frontend (includes token)
@@ -82,6 +165,28 @@ impl From<&JiraConfig> for JiraConfigMeta {
 pub struct WorktreeMemo {
     pub description: Option<String>,
     pub issue_number: Option<String>,
+    /// Pull request the worktree was checked out from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pr_number: Option<u64>,
+}
+
+/// A worktree removed in archive mode, restorable with `restore_archived_worktree`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ArchivedWorktree {
+    pub repo_path: String,
+    pub path: String,
+    /// Branch that was checked out, `None` for a detached HEAD
+    pub branch: Option<String>,
+    /// `refs/grovr/archive/...` ref keeping the archived commits alive
+    pub archive_ref: String,
+    /// Commit HEAD pointed at when archived
+    pub head: String,
+    /// Uncommitted changes were saved as a commit on top of `head`
+    pub has_changes: bool,
+    pub upstream: Option<String>,
+    pub base_branch: Option<String>,
+    pub memo: Option<WorktreeMemo>,
+    pub archived_at: i64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -95,7 +200,7 @@ pub struct AppSettings {
     #[serde(default)]
     pub default_worktree_template: Option<String>,
     #[serde(default)]
-    pub copy_paths: Option<Vec<String>>,
+    pub copy_paths: Option<Vec<CopyPathSpec>>,
     #[serde(default)]
     pub fetch_before_create: Option<bool>,
     #[serde(default)]
@@ -117,9 +222,47 @@ pub struct AppSettings {
     #[serde(default)]
     pub worktree_memos: HashMap<String, WorktreeMemo>,
     #[serde(default)]
+    pub archived_worktrees: Vec<ArchivedWorktree>,
+    #[serde(default)]
     pub global_shortcut: Option<String>,
 }
 
+impl AppSettings {
+    /// Resolves a project's overrides, then its `.grovr.toml`, against the
+    /// global settings and defaults. Unknown projects get the global values.
+    pub fn project_settings(&self, repo_path: &str) -> ProjectSettings {
+        let project = self
+            .projects
+            .iter()
+            .find(|p| p.repo_path == repo_path)
+            .map(crate::repo_config::merged);
+        let project = project.as_ref();
+        let non_empty = |s: &Option<String>| s.clone().filter(|s| !s.trim().is_empty());
+
+        let worktree_template = project
+            .and_then(|p| non_empty(&p.worktree_template))
+            .or_else(|| non_empty(&self.default_worktree_template))
+            .unwrap_or_else(|| DEFAULT_WORKTREE_TEMPLATE.to_string());
+        let copy_paths = project
+            .and_then(|p| p.copy_paths.clone())
+            .or_else(|| self.copy_paths.clone())
+            .unwrap_or_default();
+        let fetch_before_create = project
+            .and_then(|p| p.fetch_before_create)
+            .or(self.fetch_before_create)
+            .unwrap_or(true);
+        let clipboard_parse_patterns = project
+            .and_then(|p| p.clipboard_parse_patterns.clone())
+            .filter(|p| !p.is_empty())
+            .or_else(|| self.clipboard_parse_patterns.clone().filter(|p| !p.is_empty()))
+            .unwrap_or_else(|| vec![DEFAULT_CLIPBOARD_PATTERN.to_string()]);
+
+        let hooks = project.and_then(|p| p.hooks.clone()).unwrap_or_default();
+
+        ProjectSettings { worktree_template, copy_paths, fetch_before_create, clipboard_parse_patterns, hooks }
+    }
+}
+
 fn default_theme() -> String {
     "system".to_string()
 }
@@ -127,3 +270,69 @@ fn default_theme() -> String {
 fn default_refresh_interval() -> i32 {
     5
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(repo_path: &str) -> ProjectConfig {
+        ProjectConfig {
+            name: repo_path.to_string(),
+            repo_path: repo_path.to_string(),
+            default_base_branch: None,
+            ide: None,
+            emoji: None,
+            worktree_template: None,
+            copy_paths: None,
+            fetch_before_create: None,
+            clipboard_parse_patterns: None,
+            hooks: None,
+        }
+    }
+
+    #[test]
+    fn test_project_settings_fall_back_to_global() {
+        let mut go = project("/src/go-service");
+        go.copy_paths = Some(vec![CopyPathSpec::Pattern("config/dev.yaml".to_string())]);
+        go.fetch_before_create = Some(false);
+        go.worktree_template = Some(" ".to_string());
+
+        let settings = AppSettings {
+            copy_paths: Some(vec![CopyPathSpec::Entry {
+                pattern: ".env.local".to_string(),
+                mode: CopyMode::Symlink,
+                exclude: Vec::new(),
+            }]),
+            default_worktree_template: Some("{repo_parent}/wt/{branch}".to_string()),
+            projects: vec![project("/src/web"), go],
+            ..Default::default()
+        };
+
+        let web = settings.project_settings("/src/web");
+        assert_eq!(web.copy_paths[0].pattern(), ".env.local");
+        assert_eq!(web.copy_paths[0].mode(), CopyMode::Symlink);
+        assert_eq!(web.worktree_template, "{repo_parent}/wt/{branch}");
+        assert!(web.fetch_before_create);
+        assert_eq!(web.clipboard_parse_patterns, vec![DEFAULT_CLIPBOARD_PATTERN]);
+
+        let go = settings.project_settings("/src/go-service");
+        assert_eq!(go.copy_paths[0].pattern(), "config/dev.yaml");
+        assert_eq!(go.copy_paths[0].mode(), CopyMode::Copy);
+        assert!(!go.fetch_before_create);
+        // A blank override does not wipe out the template
+        assert_eq!(go.worktree_template, "{repo_parent}/wt/{branch}");
+    }
+
+    #[test]
+    fn test_copy_path_spec_accepts_plain_strings() {
+        let specs: Vec<CopyPathSpec> = serde_json::from_str(
+            r#"[".env", {"pattern": "node_modules", "mode": "symlink"}, {"pattern": "**/.env*", "exclude": ["**/dist/**"]}]"#,
+        )
+        .unwrap();
+        assert_eq!(specs[0], CopyPathSpec::Pattern(".env".to_string()));
+        assert_eq!(specs[1].mode(), CopyMode::Symlink);
+        assert_eq!(specs[2].mode(), CopyMode::Copy);
+        assert_eq!(specs[2].exclude(), ["**/dist/**"]);
+        assert_eq!(serde_json::to_string(&specs[0]).unwrap(), r#"".env""#);
+    }
+}
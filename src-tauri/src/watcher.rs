@@ -0,0 +1,183 @@
+use crate::commands::git::{self, Worktree, WorktreeStatus};
+use crate::error::GrovrError;
+use notify_debouncer_mini::notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{Emitter, Manager};
+
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Entries of the git common dir that affect worktrees. Everything else
+/// (objects, logs, hooks, FETCH_HEAD, ...) changes constantly and is ignored.
+const WATCHED_ENTRIES: &[&str] = &["HEAD", "index", "packed-refs", "config", "refs", "worktrees"];
+
+/// Filesystem watchers keyed by project repo path.
+///
+/// Dropping a debouncer stops its watcher thread, so removing an entry is all
+/// that is needed to stop watching a project.
+#[derive(Default)]
+pub struct WatcherState(pub Mutex<HashMap<String, Debouncer<RecommendedWatcher>>>);
+
+/// Payload of the `worktree-added`, `worktree-changed` and `worktree-removed` events.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorktreeEvent {
+    pub repo_path: String,
+    pub path: String,
+    /// Current worktree, `None` for `worktree-removed`
+    pub worktree: Option<Worktree>,
+    pub status: Option<WorktreeStatus>,
+}
+
+/// Starts watching a project's git metadata and pushing worktree changes to the UI.
+pub fn watch_project(app: &tauri::AppHandle, repo_path: &str) -> Result<(), GrovrError> {
+    let state = app.state::<WatcherState>();
+    let mut watchers = state.0.lock().map_err(|e| e.to_string())?;
+    if watchers.contains_key(repo_path) {
+        return Ok(());
+    }
+
+    let common_dir = git::common_dir(&git::open_repo(repo_path)?);
+    let mut snapshot = snapshot(repo_path);
+
+    let app_handle = app.clone();
+    let repo = repo_path.to_string();
+    let dir = common_dir.clone();
+    let mut debouncer = new_debouncer(DEBOUNCE, move |result: DebounceEventResult| {
+        let Ok(events) = result else { return };
+        let touched: Vec<PathBuf> = events
+            .into_iter()
+            .map(|e| e.path)
+            .filter(|p| is_relevant(&dir, p))
+            .collect();
+        if !touched.is_empty() {
+            refresh(&app_handle, &repo, &dir, &touched, &mut snapshot);
+        }
+    })
+    .map_err(|e| e.to_string())?;
+
+    debouncer
+        .watcher()
+        .watch(&common_dir, RecursiveMode::Recursive)
+        .map_err(|e| e.to_string())?;
+
+    watchers.insert(repo_path.to_string(), debouncer);
+    Ok(())
+}
+
+pub fn unwatch_project(app: &tauri::AppHandle, repo_path: &str) {
+    let state = app.state::<WatcherState>();
+    if let Ok(mut watchers) = state.0.lock() {
+        watchers.remove(repo_path);
+    }
+}
+
+fn snapshot(repo_path: &str) -> HashMap<String, Worktree> {
+    git::get_worktrees(repo_path.to_string())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|wt| (wt.path.clone(), wt))
+        .collect()
+}
+
+fn is_relevant(common_dir: &Path, path: &Path) -> bool {
+    if path.extension().is_some_and(|ext| ext == "lock") {
+        return false;
+    }
+    path.strip_prefix(common_dir)
+        .ok()
+        .and_then(|rel| rel.components().next())
+        .is_some_and(|first| WATCHED_ENTRIES.iter().any(|entry| first.as_os_str() == *entry))
+}
+
+/// Diffs the current worktree list against the last snapshot and emits events.
+fn refresh(
+    app: &tauri::AppHandle,
+    repo_path: &str,
+    common_dir: &Path,
+    touched: &[PathBuf],
+    snapshot: &mut HashMap<String, Worktree>,
+) {
+    let current = self::snapshot(repo_path);
+    let base_branch = git::get_default_branch(repo_path.to_string()).ok();
+
+    // A ref or config change can move any branch or its upstream
+    let refs_changed = touched.iter().any(|p| {
+        ["refs", "packed-refs", "config"]
+            .iter()
+            .any(|entry| p.starts_with(common_dir.join(entry)))
+    });
+
+    for (path, wt) in &current {
+        let event = match snapshot.get(path) {
+            None => "worktree-added",
+            Some(prev) if prev != wt || refs_changed || admin_files_touched(wt, common_dir, touched) => {
+                "worktree-changed"
+            }
+            Some(_) => continue,
+        };
+
+        let status = if wt.is_bare || wt.is_prunable {
+            None
+        } else {
+            git::worktree_status(path, base_branch.as_deref()).ok()
+        };
+        let _ = app.emit(
+            event,
+            WorktreeEvent {
+                repo_path: repo_path.to_string(),
+                path: path.clone(),
+                worktree: Some(wt.clone()),
+                status,
+            },
+        );
+    }
+
+    for path in snapshot.keys().filter(|p| !current.contains_key(*p)) {
+        let _ = app.emit(
+            "worktree-removed",
+            WorktreeEvent {
+                repo_path: repo_path.to_string(),
+                path: path.clone(),
+                worktree: None,
+                status: None,
+            },
+        );
+    }
+
+    *snapshot = current;
+}
+
+/// Whether a worktree's own HEAD or index changed. The main worktree keeps
+/// these at the root of the common dir, linked ones under `worktrees/<name>`.
+fn admin_files_touched(wt: &Worktree, common_dir: &Path, touched: &[PathBuf]) -> bool {
+    if wt.is_main {
+        touched
+            .iter()
+            .any(|p| *p == common_dir.join("HEAD") || *p == common_dir.join("index"))
+    } else {
+        touched.iter().any(|p| p.starts_with(&wt.gitdir))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_relevant() {
+        let dir = Path::new("/repo/.git");
+        assert!(is_relevant(dir, &dir.join("HEAD")));
+        assert!(is_relevant(dir, &dir.join("refs/heads/feature")));
+        assert!(is_relevant(dir, &dir.join("worktrees/feature/HEAD")));
+        assert!(is_relevant(dir, &dir.join("worktrees/feature/index")));
+        assert!(!is_relevant(dir, &dir.join("index.lock")));
+        assert!(!is_relevant(dir, &dir.join("refs/heads/feature.lock")));
+        assert!(!is_relevant(dir, &dir.join("objects/ab/cdef")));
+        assert!(!is_relevant(dir, &dir.join("FETCH_HEAD")));
+        assert!(!is_relevant(dir, Path::new("/elsewhere/HEAD")));
+    }
+}
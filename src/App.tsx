@@ -1,4 +1,4 @@
-import { useEffect, useState, useCallback, useRef } from 'react';
+import { useEffect, useState, useCallback } from 'react';
 import { WorktreeListPage } from '@/pages/WorktreeListPage';
 import { SettingsPage } from '@/pages/SettingsPage';
 import { ProjectSettingsPage } from '@/pages/ProjectSettingsPage';
@@ -32,6 +32,24 @@ function applyTheme(theme: ThemeMode) {
   }
 }
 
+// Try each pattern until one matches
+function parseClipboard(text: string, patterns: string[]): ParsedClipboard | null {
+  for (const pattern of patterns) {
+    try {
+      const match = text.match(new RegExp(pattern));
+      if (match?.groups) {
+        return {
+          issueNumber: match.groups.issueNumber || '',
+          description: match.groups.description || '',
+        };
+      }
+    } catch {
+      // Invalid regex, skip
+    }
+  }
+  return null;
+}
+
 function App() {
   const [page, setPage] = useState<Page>('worktrees');
   const [selectedProject, setSelectedProject] = useState<Project | null>(null);
@@ -42,18 +60,14 @@ function App() {
   const [expandedProjects, setExpandedProjects] = useState<Set<string>>(new Set());
   const [updateInfo, setUpdateInfo] = useState<UpdateInfo | null>(null);
   const [showUpdateDialog, setShowUpdateDialog] = useState(false);
-  const clipboardPatternsRef = useRef<string[]>(['\\[(?<issueNumber>[A-Z]+-\\d+)\\]\\s*(?<description>.+)']);
 
-  // Load saved theme and clipboard pattern on startup
+  // Load saved theme on startup
   useEffect(() => {
     api.getSettings()
       .then((settings) => {
         const savedTheme = (settings.theme as ThemeMode) || 'system';
         setTheme(savedTheme);
         applyTheme(savedTheme);
-        if (settings.clipboard_parse_patterns && settings.clipboard_parse_patterns.length > 0) {
-          clipboardPatternsRef.current = settings.clipboard_parse_patterns;
-        }
       })
       .catch(() => {
         applyTheme('system');
@@ -103,39 +117,28 @@ function App() {
 
         try {
           const text = await api.readClipboardText();
-          if (!text || clipboardPatternsRef.current.length === 0) return;
+          if (!text) return;
 
-          // Try each pattern until one matches
+          // Projects can override the patterns; the first project whose patterns match wins
+          const projects = await api.getProjects();
           let matchedData: ParsedClipboard | null = null;
-          for (const pattern of clipboardPatternsRef.current) {
-            try {
-              const regex = new RegExp(pattern);
-              const match = text.match(regex);
-              if (match?.groups) {
-                matchedData = {
-                  issueNumber: match.groups.issueNumber || '',
-                  description: match.groups.description || '',
-                };
-                break;
-              }
-            } catch {
-              // Invalid regex, skip
+          let matchedProject: api.BackendProjectConfig | null = null;
+          for (const project of projects) {
+            const { clipboard_parse_patterns: patterns } = await api.getProjectSettings(project.repo_path);
+            matchedData = parseClipboard(text, patterns);
+            if (matchedData) {
+              matchedProject = project;
+              break;
             }
           }
 
-          if (matchedData) {
+          if (matchedData && matchedProject) {
             e.preventDefault();
-
-            // Load projects and select the first one
-            const projects = await api.getProjects();
-            if (projects.length === 0) return;
-
-            const firstProject = projects[0];
             setSelectedProject({
-              name: firstProject.name,
-              repoPath: firstProject.repo_path,
-              defaultBaseBranch: firstProject.default_base_branch,
-              ide: firstProject.ide?.preset as IDEPreset | undefined,
+              name: matchedProject.name,
+              repoPath: matchedProject.repo_path,
+              defaultBaseBranch: matchedProject.default_base_branch,
+              ide: matchedProject.ide?.preset as IDEPreset | undefined,
               worktrees: [],
             });
             setClipboardData(matchedData);
@@ -1,4 +1,46 @@
 import { invoke } from '@tauri-apps/api/core';
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
+
+// ============ Errors from Backend ============
+
+export type GrovrErrorKind =
+  | 'git_not_installed'
+  | 'not_a_git_repo'
+  | 'dirty_worktree'
+  | 'branch_not_merged'
+  | 'branch_exists'
+  | 'path_exists'
+  | 'worktree_locked'
+  | 'removal_not_acknowledged'
+  | 'auth_failed'
+  | 'rate_limited'
+  | 'timeout'
+  | 'cancelled'
+  | 'api'
+  | 'git'
+  | 'other';
+
+export interface GrovrError {
+  kind: GrovrErrorKind;
+  message: string;
+  path?: string;
+  branch?: string;
+  reason?: string | null;
+  plan_id?: string;
+  service?: string;
+  status?: number;
+  reset_at?: number | null;
+  seconds?: number;
+}
+
+export function isGrovrError(err: unknown): err is GrovrError {
+  return typeof err === 'object' && err !== null && 'kind' in err && 'message' in err;
+}
+
+export function getErrorMessage(err: unknown): string {
+  if (isGrovrError(err)) return err.message;
+  return err instanceof Error ? err.message : String(err);
+}
 
 // ============ Types from Backend ============
 
@@ -7,6 +49,14 @@ export interface BackendWorktree {
   branch: string;
   is_main: boolean;
   is_bare: boolean;
+  head?: string;
+  is_detached: boolean;
+  is_locked: boolean;
+  lock_reason?: string;
+  is_prunable: boolean;
+  prunable_reason?: string;
+  gitdir: string;
+  created_at?: number;
 }
 
 export interface BackendBranch {
@@ -15,11 +65,26 @@ export interface BackendBranch {
   is_head: boolean;
 }
 
+export interface BackendCommitInfo {
+  sha: string;
+  summary: string;
+  author: string;
+  time: number;
+}
+
 export interface BackendWorktreeStatus {
   has_changes: boolean;
   staged: number;
   unstaged: number;
   untracked: number;
+  upstream?: string;
+  upstream_gone: boolean;
+  ahead?: number;
+  behind?: number;
+  base_branch?: string;
+  ahead_of_base?: number;
+  behind_base?: number;
+  last_commit?: BackendCommitInfo;
 }
 
 export interface BackendIdeConfig {
@@ -28,11 +93,78 @@ export interface BackendIdeConfig {
   custom_command?: string;
 }
 
+export type CopyMode = 'copy' | 'symlink' | 'hardlink' | 'reflink';
+
+/**
+ * A path or glob to copy into new worktrees. Plain strings use copy mode;
+ * a leading `!` excludes matching paths from every entry.
+ */
+export type CopyPathSpec = string | { pattern: string; mode?: CopyMode; exclude?: string[] };
+
+export function copyPathPattern(spec: CopyPathSpec): string {
+  return typeof spec === 'string' ? spec : spec.pattern;
+}
+
+export function copyPathMode(spec: CopyPathSpec): CopyMode {
+  return typeof spec === 'string' ? 'copy' : spec.mode || 'copy';
+}
+
+/** `pattern` or `pattern (mode)`, the one-line form used in settings */
+export function formatCopyPath(spec: CopyPathSpec): string {
+  const mode = copyPathMode(spec);
+  return mode === 'copy' ? copyPathPattern(spec) : `${copyPathPattern(spec)} (${mode})`;
+}
+
+export function parseCopyPath(line: string): CopyPathSpec {
+  const match = line.trim().match(/^(.+?)\s+\((copy|symlink|hardlink|reflink)\)$/);
+  if (!match || match[2] === 'copy') return match ? match[1] : line.trim();
+  return { pattern: match[1], mode: match[2] as CopyMode };
+}
+
 export interface BackendProjectConfig {
   name: string;
   repo_path: string;
   default_base_branch?: string;
   ide?: BackendIdeConfig;
+  emoji?: string;
+  worktree_template?: string;
+  copy_paths?: CopyPathSpec[];
+  fetch_before_create?: boolean;
+  clipboard_parse_patterns?: string[];
+  hooks?: ProjectHooks;
+}
+
+/** Shell commands run in a worktree after it is created and before it is removed */
+export interface ProjectHooks {
+  post_create: string[];
+  pre_remove: string[];
+  timeout_secs?: number;
+}
+
+/** Settings a repository ships in its `.grovr.toml` */
+export interface RepoConfig {
+  default_base_branch?: string;
+  ide?: string;
+  worktree_template?: string;
+  copy_paths?: CopyPathSpec[];
+  fetch_before_create?: boolean;
+  clipboard_parse_patterns?: string[];
+  hooks?: ProjectHooks;
+}
+
+/** A project's local settings and its `.grovr.toml`, kept apart for editing */
+export interface ProjectConfigSources {
+  local: BackendProjectConfig;
+  repo: RepoConfig | null;
+}
+
+/** Effective worktree settings of a project (project overrides over global settings) */
+export interface ProjectSettings {
+  worktree_template: string;
+  copy_paths: CopyPathSpec[];
+  fetch_before_create: boolean;
+  clipboard_parse_patterns: string[];
+  hooks: ProjectHooks;
 }
 
 export interface BackendAppSettings {
@@ -40,7 +172,7 @@ export interface BackendAppSettings {
   theme: string;
   launch_at_startup?: boolean;
   default_worktree_template?: string;
-  copy_paths?: string[];
+  copy_paths?: CopyPathSpec[];
   fetch_before_create?: boolean;
   clipboard_parse_patterns?: string[];
   last_used_project?: string;
@@ -50,6 +182,7 @@ export interface BackendAppSettings {
   projects: BackendProjectConfig[];
   github_configs: unknown[];
   jira_configs: unknown[];
+  archived_worktrees: ArchivedWorktree[];
   global_shortcut?: string;
 }
 
@@ -81,7 +214,7 @@ export async function setDefaultWorktreeTemplate(template: string): Promise<void
   return invoke('set_default_worktree_template', { template });
 }
 
-export async function setCopyPaths(paths: string[]): Promise<void> {
+export async function setCopyPaths(paths: CopyPathSpec[]): Promise<void> {
   return invoke('set_copy_paths', { paths });
 }
 
@@ -119,6 +252,14 @@ export async function getProjects(): Promise<BackendProjectConfig[]> {
   return invoke('get_projects');
 }
 
+export async function getProjectConfig(repoPath: string): Promise<ProjectConfigSources> {
+  return invoke('get_project_config', { repoPath });
+}
+
+export async function getProjectSettings(repoPath: string): Promise<ProjectSettings> {
+  return invoke('get_project_settings', { repoPath });
+}
+
 export async function addProject(project: BackendProjectConfig): Promise<void> {
   return invoke('add_project', { project });
 }
@@ -150,6 +291,22 @@ export async function createWorktree(
   return invoke('create_worktree', { repoPath, worktreePath, branchName, baseBranch });
 }
 
+export interface PullRequestWorktree {
+  path: string;
+  branch: string;
+  pr_number: number;
+  head_sha: string;
+  is_fork: boolean;
+}
+
+export async function createWorktreeFromPr(
+  repoPath: string,
+  prNumber: number,
+  worktreePath: string
+): Promise<PullRequestWorktree> {
+  return invoke('create_worktree_from_pr', { repoPath, prNumber, worktreePath });
+}
+
 export async function createWorktreeExistingBranch(
   repoPath: string,
   worktreePath: string,
@@ -163,19 +320,196 @@ export async function removeWorktree(
   worktreePath: string,
   force: boolean,
   deleteBranch: boolean = false,
-  branchName?: string
+  branchName?: string,
+  allowLocked: boolean = false,
+  safe: boolean = false,
+  acknowledgedPlan?: string
 ): Promise<void> {
-  return invoke('remove_worktree', { repoPath, worktreePath, force, deleteBranch, branchName });
+  return invoke('remove_worktree', {
+    repoPath,
+    worktreePath,
+    force,
+    deleteBranch,
+    branchName,
+    allowLocked,
+    safe,
+    acknowledgedPlan,
+  });
+}
+
+export interface StashEntry {
+  index: number;
+  message: string;
+}
+
+export interface RemovalPlan {
+  plan_id: string;
+  path: string;
+  branch?: string;
+  dirty_files: string[];
+  unpushed_commits: BackendCommitInfo[];
+  unpushed_count: number;
+  stashes: StashEntry[];
+  branch_merged?: boolean;
+  base_branch?: string;
+}
+
+export async function planRemoveWorktree(repoPath: string, worktreePath: string): Promise<RemovalPlan> {
+  return invoke('plan_remove_worktree', { repoPath, worktreePath });
+}
+
+export async function pruneWorktrees(repoPath: string, includeLocked: boolean = false): Promise<void> {
+  return invoke('prune_worktrees', { repoPath, includeLocked });
+}
+
+export async function lockWorktree(repoPath: string, worktreePath: string, reason?: string): Promise<void> {
+  return invoke('lock_worktree', { repoPath, worktreePath, reason: reason ?? null });
+}
+
+export async function unlockWorktree(repoPath: string, worktreePath: string): Promise<void> {
+  return invoke('unlock_worktree', { repoPath, worktreePath });
+}
+
+export interface WorktreeRelocation {
+  from: string;
+  to: string;
+  moved: boolean;
+  error?: string;
+}
+
+export async function moveWorktree(repoPath: string, from: string, to: string): Promise<string> {
+  return invoke('move_worktree', { repoPath, from, to });
 }
 
-export async function pruneWorktrees(repoPath: string): Promise<void> {
-  return invoke('prune_worktrees', { repoPath });
+export async function relocateWorktrees(repoPath: string, dryRun: boolean): Promise<WorktreeRelocation[]> {
+  return invoke('relocate_worktrees', { repoPath, dryRun });
+}
+
+export type PathCollision = { kind: 'worktree'; branch: string } | { kind: 'directory' };
+
+export interface ResolvedWorktreePath {
+  path: string;
+  collision?: PathCollision;
+  available_path: string;
+}
+
+export async function resolveWorktreePath(
+  repoPath: string,
+  branch: string,
+  description?: string,
+  issue?: string
+): Promise<ResolvedWorktreePath> {
+  return invoke('resolve_worktree_path', { repoPath, branch, description, issue });
 }
 
 export async function getWorktreeStatus(worktreePath: string): Promise<BackendWorktreeStatus> {
   return invoke('get_worktree_status', { worktreePath });
 }
 
+export interface WorktreeStatusResult {
+  status?: BackendWorktreeStatus;
+  error?: GrovrError;
+}
+
+export async function getProjectStatus(repoPath: string): Promise<Record<string, WorktreeStatusResult>> {
+  return invoke('get_project_status', { repoPath });
+}
+
+// ============ Git - Stale Worktrees ============
+
+export interface StaleCriteria {
+  merged?: boolean;
+  upstream_gone?: boolean;
+  inactive_days?: number;
+  pr_closed?: boolean;
+  jira_done?: boolean;
+}
+
+export type StaleReason =
+  | { kind: 'merged'; base_branch: string }
+  | { kind: 'upstream_gone'; upstream: string }
+  | { kind: 'inactive'; days: number }
+  | { kind: 'pr_closed'; number: number; merged: boolean; url: string }
+  | { kind: 'jira_done'; key: string; status: string };
+
+export interface StaleWorktree {
+  worktree: BackendWorktree;
+  reasons: StaleReason[];
+  status?: BackendWorktreeStatus;
+}
+
+export interface WorktreeCleanup {
+  path: string;
+  branch?: string;
+  removed: boolean;
+  error?: GrovrError;
+}
+
+export async function findStaleWorktrees(repoPath: string, criteria: StaleCriteria): Promise<StaleWorktree[]> {
+  return invoke('find_stale_worktrees', { repoPath, criteria });
+}
+
+export async function cleanupWorktrees(
+  repoPath: string,
+  worktreePaths: string[],
+  force: boolean,
+  deleteBranch: boolean
+): Promise<WorktreeCleanup[]> {
+  return invoke('cleanup_worktrees', { repoPath, worktreePaths, force, deleteBranch });
+}
+
+// ============ Git - Archive ============
+
+export interface ArchivedWorktree {
+  repo_path: string;
+  path: string;
+  branch?: string;
+  archive_ref: string;
+  head: string;
+  has_changes: boolean;
+  upstream?: string;
+  base_branch?: string;
+  memo?: WorktreeMemo;
+  archived_at: number;
+}
+
+export async function archiveWorktree(repoPath: string, worktreePath: string): Promise<ArchivedWorktree> {
+  return invoke('archive_worktree', { repoPath, worktreePath });
+}
+
+export async function getArchivedWorktrees(repoPath: string): Promise<ArchivedWorktree[]> {
+  return invoke('get_archived_worktrees', { repoPath });
+}
+
+export async function restoreArchivedWorktree(repoPath: string, archiveRef: string): Promise<string> {
+  return invoke('restore_archived_worktree', { repoPath, archiveRef });
+}
+
+export async function deleteArchivedWorktree(repoPath: string, archiveRef: string): Promise<void> {
+  return invoke('delete_archived_worktree', { repoPath, archiveRef });
+}
+
+// ============ Git - Worktree Events ============
+
+export type WorktreeEventName = 'worktree-added' | 'worktree-changed' | 'worktree-removed';
+
+export interface WorktreeEvent {
+  repo_path: string;
+  path: string;
+  worktree?: BackendWorktree;
+  status?: BackendWorktreeStatus;
+}
+
+export async function onWorktreeEvents(
+  handler: (event: WorktreeEventName, payload: WorktreeEvent) => void
+): Promise<UnlistenFn> {
+  const names: WorktreeEventName[] = ['worktree-added', 'worktree-changed', 'worktree-removed'];
+  const unlisteners = await Promise.all(
+    names.map((name) => listen<WorktreeEvent>(name, (e) => handler(name, e.payload)))
+  );
+  return () => unlisteners.forEach((unlisten) => unlisten());
+}
+
 // ============ Git - Branch API ============
 
 export async function getBranches(repoPath: string, includeRemote: boolean): Promise<BackendBranch[]> {
@@ -208,6 +542,47 @@ export async function gitPull(worktreePath: string): Promise<void> {
   return invoke('git_pull', { worktreePath });
 }
 
+export type OperationKind = 'fetch' | 'pull' | 'create_worktree';
+export type OperationStatus = 'running' | 'succeeded' | 'failed' | 'cancelled';
+
+export interface OperationRecord {
+  id: number;
+  kind: OperationKind;
+  path: string;
+  status: OperationStatus;
+  error: string | null;
+  started_at: number;
+  duration_ms: number | null;
+}
+
+export interface OperationProgress {
+  id: number;
+  line: string;
+  percent: number | null;
+}
+
+export async function cancelOperation(id: number): Promise<boolean> {
+  return invoke('cancel_operation', { id });
+}
+
+/** Running and recent operations, newest first */
+export async function getOperations(): Promise<OperationRecord[]> {
+  return invoke('get_operations');
+}
+
+export async function onOperationEvents(handlers: {
+  started?: (record: OperationRecord) => void;
+  progress?: (progress: OperationProgress) => void;
+  finished?: (record: OperationRecord) => void;
+}): Promise<UnlistenFn> {
+  const unlisteners = await Promise.all([
+    listen<OperationRecord>('operation-started', (e) => handlers.started?.(e.payload)),
+    listen<OperationProgress>('operation-progress', (e) => handlers.progress?.(e.payload)),
+    listen<OperationRecord>('operation-finished', (e) => handlers.finished?.(e.payload)),
+  ]);
+  return () => unlisteners.forEach((unlisten) => unlisten());
+}
+
 // ============ Git - Remote Info API ============
 
 export interface GitHubRemoteInfo {
@@ -233,19 +608,108 @@ export async function openTerminal(path: string): Promise<void> {
   return invoke('open_terminal', { path });
 }
 
+export interface CopyReportEntry {
+  path: string;
+  mode: CopyMode;
+  reason: string | null;
+}
+
+export interface CopyReport {
+  copied: CopyReportEntry[];
+  skipped: CopyReportEntry[];
+  failed: CopyReportEntry[];
+}
+
 export async function copyPathsToWorktree(
   sourcePath: string,
   targetPath: string,
-  paths: string[]
-): Promise<void> {
+  paths: CopyPathSpec[]
+): Promise<CopyReport> {
   return invoke('copy_paths_to_worktree', { sourcePath, targetPath, paths });
 }
 
+// ============ Hooks API ============
+
+export type HookStage = 'post_create' | 'pre_remove';
+export type HookStatus = 'succeeded' | 'failed' | 'timed_out' | 'cancelled';
+
+export interface HookOutcome {
+  command: string;
+  status: HookStatus;
+  exit_code: number | null;
+  duration_ms: number;
+}
+
+export type HookEventName = 'hook-started' | 'hook-output' | 'hook-finished';
+
+export interface HookEvent {
+  worktree_path: string;
+  stage: HookStage;
+  command: string;
+  stream: 'stdout' | 'stderr' | null;
+  line: string | null;
+  outcome: HookOutcome | null;
+}
+
+export interface HookRunResult {
+  stage: HookStage;
+  hooks: HookOutcome[];
+  success: boolean;
+}
+
+export async function runWorktreeHooks(
+  repoPath: string,
+  worktreePath: string,
+  stage: HookStage,
+  branch: string,
+  baseBranch?: string,
+  issue?: string
+): Promise<HookRunResult> {
+  return invoke('run_worktree_hooks', {
+    repoPath,
+    worktreePath,
+    stage,
+    branch,
+    baseBranch: baseBranch ?? null,
+    issue: issue ?? null,
+  });
+}
+
+export async function cancelWorktreeHooks(worktreePath: string): Promise<boolean> {
+  return invoke('cancel_worktree_hooks', { worktreePath });
+}
+
+export async function onHookEvents(
+  handler: (event: HookEventName, payload: HookEvent) => void
+): Promise<UnlistenFn> {
+  const names: HookEventName[] = ['hook-started', 'hook-output', 'hook-finished'];
+  const unlisteners = await Promise.all(
+    names.map((name) => listen<HookEvent>(name, (e) => handler(name, e.payload)))
+  );
+  return () => unlisteners.forEach((unlisten) => unlisten());
+}
+
+/** Runs a worktree's pre-remove hooks, throwing with the failing command unless `force` */
+export async function runPreRemoveHooks(
+  repoPath: string,
+  worktreePath: string,
+  branch: string,
+  issue: string | undefined,
+  force: boolean
+): Promise<void> {
+  const result = await runWorktreeHooks(repoPath, worktreePath, 'pre_remove', branch, undefined, issue);
+  const failed = result.hooks.find((h) => h.status !== 'succeeded');
+  if (failed && !force) {
+    throw new Error(`Pre-remove hook "${failed.command}" ${failed.status.replace('_', ' ')}`);
+  }
+}
+
 // ============ Worktree Memo API ============
 
 export interface WorktreeMemo {
   description?: string;
   issue_number?: string;
+  pr_number?: number;
 }
 
 export async function getWorktreeMemo(path: string): Promise<WorktreeMemo> {
@@ -318,6 +782,29 @@ export async function fetchPullRequests(
   return invoke('fetch_pull_requests', { owner, repo, branch });
 }
 
+export interface PullRequestHead {
+  number: number;
+  title: string;
+  state: string;
+  url: string;
+  head_ref: string;
+  head_sha: string;
+  head_repo: string | null;
+  head_clone_url: string | null;
+  is_fork: boolean;
+}
+
+export async function fetchPullRequest(owner: string, repo: string, number: number): Promise<PullRequestHead> {
+  return invoke('fetch_pull_request', { owner, repo, number });
+}
+
+/** Local branch `create_worktree_from_pr` creates: the head branch, prefixed with the fork owner for forks */
+export function pullRequestBranchName(pr: PullRequestHead): string {
+  if (!pr.is_fork) return pr.head_ref;
+  const owner = pr.head_repo?.split('/')[0];
+  return owner ? `${owner}/${pr.head_ref}` : `pr-${pr.number}`;
+}
+
 // ============ Jira Integration API ============
 
 // Full config (used when saving - token sent to backend)
@@ -62,7 +62,7 @@ export function AddProjectPage({ onBack, onProjectAdded }: AddProjectPageProps)
       onProjectAdded(repoPath.trim());
       onBack();
     } catch (err) {
-      setError(err instanceof Error ? err.message : String(err));
+      setError(api.getErrorMessage(err));
     } finally {
       setLoading(false);
     }
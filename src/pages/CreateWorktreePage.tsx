@@ -28,12 +28,19 @@ export function CreateWorktreePage({ project: initialProject, onBack, onWorktree
   const [description, setDescription] = useState(initialData?.description || '');
   const [baseBranch, setBaseBranch] = useState('');
   const [worktreePath, setWorktreePath] = useState('');
+  const [pathCollision, setPathCollision] = useState<api.PathCollision | null>(null);
   const [openIDE, setOpenIDE] = useState(true);
   const [fetchBeforeCreate, setFetchBeforeCreate] = useState(true);
   const [branches, setBranches] = useState<{ local: string[]; remote: string[] }>({ local: [], remote: [] });
   const [loading, setLoading] = useState(false);
   const [error, setError] = useState('');
   const [settings, setSettings] = useState<api.BackendAppSettings | null>(null);
+  const [projectSettings, setProjectSettings] = useState<api.ProjectSettings | null>(null);
+  const [prNumber, setPrNumber] = useState('');
+  const [pullRequest, setPullRequest] = useState<api.PullRequestHead | null>(null);
+  const [operation, setOperation] = useState<api.OperationProgress | null>(null);
+  const [runningHooks, setRunningHooks] = useState(false);
+  const [hookLog, setHookLog] = useState<string[]>([]);
 
   useEffect(() => {
     if (allowProjectChange) {
@@ -45,27 +52,54 @@ export function CreateWorktreePage({ project: initialProject, onBack, onWorktree
     loadInitialData();
   }, [selectedProject]);
 
-  // Auto-generate path when branch name or description changes
+  // Auto-generate path when branch name, description or issue changes
   useEffect(() => {
-    if (branchName && selectedProject) {
-      const template = settings?.default_worktree_template || '{project}.worktrees/{branch}-{description}';
-      const descriptionSlug = description
-        .trim()
-        .replace(/[\/:*?"<>|\\&;'`$#%!()[\]{}^~@+=,]/g, '_')
-        .replace(/\s+/g, '-');
-      const parentPath = selectedProject.repoPath.split('/').slice(0, -1).join('/');
-      const projectName = selectedProject.repoPath.split('/').pop() || selectedProject.name;
-
-      let path = template
-        .replace('{project}', `${parentPath}/${projectName}`)
-        .replace('{branch}', branchName)
-        .replace('{description}', descriptionSlug);
-      path = path.replace(/-$/, '');
-      setWorktreePath(path);
-    } else {
+    if (!branchName || !selectedProject) {
       setWorktreePath('');
+      setPathCollision(null);
+      return;
     }
-  }, [branchName, description, selectedProject, settings]);
+    let cancelled = false;
+    api.resolveWorktreePath(selectedProject.repoPath, branchName, description, issueNumber)
+      .then((resolved) => {
+        if (cancelled) return;
+        setWorktreePath(resolved.path);
+        setPathCollision(resolved.collision ?? null);
+      })
+      .catch((err) => {
+        if (!cancelled) setError(api.getErrorMessage(err));
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [branchName, description, issueNumber, selectedProject, projectSettings]);
+
+  // Look up the pull request and name the branch after its head
+  useEffect(() => {
+    setPullRequest(null);
+    const number = parseInt(prNumber.replace('#', ''), 10);
+    if (!number || !selectedProject) return;
+    let cancelled = false;
+    const timer = setTimeout(async () => {
+      try {
+        const config = await api.getGitHubConfig();
+        const remote = await api.getGitHubRemoteInfo(selectedProject.repoPath, config?.host);
+        if (!remote) throw new Error('The origin remote is not a GitHub repository');
+        const pr = await api.fetchPullRequest(remote.owner, remote.repo, number);
+        if (cancelled) return;
+        setPullRequest(pr);
+        setBranchName(api.pullRequestBranchName(pr));
+        setDescription((current) => current || pr.title);
+        setError('');
+      } catch (err) {
+        if (!cancelled) setError(api.getErrorMessage(err));
+      }
+    }, 400);
+    return () => {
+      cancelled = true;
+      clearTimeout(timer);
+    };
+  }, [prNumber, selectedProject]);
 
   const loadProjects = async () => {
     try {
@@ -96,12 +130,14 @@ export function CreateWorktreePage({ project: initialProject, onBack, onWorktree
   const loadInitialData = async () => {
     if (!selectedProject) return;
     try {
-      const [settingsData, branchList] = await Promise.all([
+      const [settingsData, projectSettingsData, branchList] = await Promise.all([
         api.getSettings(),
+        api.getProjectSettings(selectedProject.repoPath),
         api.getBranches(selectedProject.repoPath, true),
       ]);
       setSettings(settingsData);
-      setFetchBeforeCreate(settingsData.fetch_before_create ?? true);
+      setProjectSettings(projectSettingsData);
+      setFetchBeforeCreate(projectSettingsData.fetch_before_create);
 
       // Separate local and remote branches
       const local: string[] = [];
@@ -145,25 +181,43 @@ export function CreateWorktreePage({ project: initialProject, onBack, onWorktree
       setError('');
     });
 
+    // Show git progress for the fetch and checkout below, and allow stopping them
+    const repoPath = selectedProject.repoPath;
+    const targetPath = worktreePath.trim();
+    const unlistenOperations = await api.onOperationEvents({
+      started: (record) => {
+        if (record.path === repoPath || record.path === targetPath) {
+          setOperation({ id: record.id, line: '', percent: null });
+        }
+      },
+      progress: (progress) => setOperation((current) => (current?.id === progress.id ? progress : current)),
+      finished: (record) => setOperation((current) => (current?.id === record.id ? null : current)),
+    });
+
     try {
       // Fetch if needed
-      if (fetchBeforeCreate && baseBranch.startsWith('origin/')) {
+      if (!pullRequest && fetchBeforeCreate && baseBranch.startsWith('origin/')) {
         await api.gitFetch(selectedProject.repoPath);
       }
 
-      // Create worktree
-      await api.createWorktree(
-        selectedProject.repoPath,
-        worktreePath.trim(),
-        branchName.trim(),
-        baseBranch.trim()
-      );
+      // Create worktree, from the pull request head when one is given
+      if (pullRequest) {
+        await api.createWorktreeFromPr(selectedProject.repoPath, pullRequest.number, worktreePath.trim());
+      } else {
+        await api.createWorktree(
+          selectedProject.repoPath,
+          worktreePath.trim(),
+          branchName.trim(),
+          baseBranch.trim()
+        );
+      }
 
       // Save memo if provided
       if (description || issueNumber) {
         await api.setWorktreeMemo(worktreePath.trim(), {
           description: description || undefined,
           issue_number: issueNumber || undefined,
+          pr_number: pullRequest?.number,
         });
       }
 
@@ -171,13 +225,33 @@ export function CreateWorktreePage({ project: initialProject, onBack, onWorktree
       await api.setLastUsedProject(selectedProject.repoPath);
 
       // Copy paths if configured
-      const copyPaths = settings?.copy_paths || [];
+      const copyPaths = projectSettings?.copy_paths || [];
       if (copyPaths.length > 0) {
         // Find main worktree path
         const worktrees = await api.getWorktrees(selectedProject.repoPath);
         const mainWorktree = worktrees.find((w) => w.is_main);
         if (mainWorktree) {
-          await api.copyPathsToWorktree(mainWorktree.path, worktreePath.trim(), copyPaths);
+          const report = await api.copyPathsToWorktree(mainWorktree.path, worktreePath.trim(), copyPaths);
+          if (report.failed.length > 0) {
+            // The worktree exists at this point; stay on the page so the failures are visible
+            onWorktreeCreated();
+            setError(
+              `Worktree created, but some files could not be copied:\n` +
+                report.failed.map((f) => `${f.path}: ${f.reason}`).join('\n')
+            );
+            return;
+          }
+        }
+      }
+
+      // Run post-create hooks, streaming their output into the log
+      if ((projectSettings?.hooks.post_create || []).length > 0) {
+        const result = await runPostCreateHooks();
+        if (!result.success) {
+          const failed = result.hooks.find((h) => h.status !== 'succeeded');
+          onWorktreeCreated();
+          setError(`Worktree created, but hook "${failed?.command}" ${failed?.status.replace('_', ' ')}`);
+          return;
         }
       }
 
@@ -191,12 +265,42 @@ export function CreateWorktreePage({ project: initialProject, onBack, onWorktree
       onWorktreeCreated();
       onBack();
     } catch (err) {
-      setError(err instanceof Error ? err.message : String(err));
+      setError(api.getErrorMessage(err));
     } finally {
+      unlistenOperations();
+      setOperation(null);
       setLoading(false);
     }
   };
 
+  const runPostCreateHooks = async () => {
+    const path = worktreePath.trim();
+    setHookLog([]);
+    setRunningHooks(true);
+    const unlisten = await api.onHookEvents((event, payload) => {
+      if (payload.worktree_path !== path) return;
+      if (event === 'hook-started') {
+        setHookLog((log) => [...log, `$ ${payload.command}`]);
+      } else if (event === 'hook-output' && payload.line != null) {
+        const line = payload.line;
+        setHookLog((log) => [...log, line]);
+      }
+    });
+    try {
+      return await api.runWorktreeHooks(
+        selectedProject.repoPath,
+        path,
+        'post_create',
+        branchName.trim(),
+        baseBranch.trim(),
+        issueNumber || undefined
+      );
+    } finally {
+      unlisten();
+      setRunningHooks(false);
+    }
+  };
+
   const handleSubmit = (e: React.FormEvent) => {
     e.preventDefault();
     doSubmit();
@@ -206,7 +310,7 @@ export function CreateWorktreePage({ project: initialProject, onBack, onWorktree
   useKeyboardShortcut({ key: 'Enter', cmdOrCtrl: true }, doSubmit, !loading && !!branchName.trim() && !!worktreePath.trim());
 
   const isRemoteBranch = baseBranch.startsWith('origin/') || branches.remote.includes(baseBranch);
-  const copyPaths = settings?.copy_paths || [];
+  const copyPaths = projectSettings?.copy_paths || [];
 
   return (
     <div className="h-full flex flex-col">
@@ -256,6 +360,7 @@ export function CreateWorktreePage({ project: initialProject, onBack, onWorktree
                     className="settings-select w-full"
                     value={baseBranch}
                     onChange={(e) => setBaseBranch(e.target.value)}
+                    disabled={!!pullRequest}
                   >
                     {branches.local.length > 0 && (
                       <optgroup label="Local">
@@ -289,6 +394,24 @@ export function CreateWorktreePage({ project: initialProject, onBack, onWorktree
                   )}
                 </div>
 
+                {/* Pull Request */}
+                <div className="settings-item-full">
+                  <label className="settings-label">Pull Request (optional)</label>
+                  <input
+                    type="text"
+                    className="settings-input font-mono"
+                    value={prNumber}
+                    onChange={(e) => setPrNumber(e.target.value)}
+                    placeholder="#123"
+                  />
+                  {pullRequest && (
+                    <div className="text-xs text-muted-foreground mt-1">
+                      {pullRequest.title} ({pullRequest.is_fork ? `${pullRequest.head_repo ?? 'deleted fork'}:` : ''}
+                      {pullRequest.head_ref})
+                    </div>
+                  )}
+                </div>
+
                 {/* Issue Number */}
                 <div className="settings-item-full">
                   <label className="settings-label">Issue Number</label>
@@ -310,6 +433,7 @@ export function CreateWorktreePage({ project: initialProject, onBack, onWorktree
                     value={branchName}
                     onChange={(e) => setBranchName(e.target.value)}
                     placeholder="ABC-1234"
+                    readOnly={!!pullRequest}
                   />
                 </div>
 
@@ -335,6 +459,13 @@ export function CreateWorktreePage({ project: initialProject, onBack, onWorktree
                     onChange={(e) => setWorktreePath(e.target.value)}
                     placeholder="Path will be generated automatically"
                   />
+                  {pathCollision && (
+                    <div className="text-xs text-muted-foreground mt-1">
+                      {pathCollision.kind === 'worktree'
+                        ? `A worktree for ${pathCollision.branch || 'a detached HEAD'} already exists at this path`
+                        : 'A directory already exists at this path'}
+                    </div>
+                  )}
                 </div>
 
                 {/* Copy paths */}
@@ -344,7 +475,7 @@ export function CreateWorktreePage({ project: initialProject, onBack, onWorktree
                     <div className="flex flex-wrap gap-1">
                       {copyPaths.map((p, i) => (
                         <span key={i} className="px-2 py-0.5 text-xs font-mono bg-muted rounded">
-                          {p}
+                          {api.formatCopyPath(p)}
                         </span>
                       ))}
                     </div>
@@ -366,19 +497,45 @@ export function CreateWorktreePage({ project: initialProject, onBack, onWorktree
                   </label>
                 </div>
 
-                {error && <div className="text-xs text-red-500 mt-2">{error}</div>}
+                {operation && (
+                  <div className="text-xs text-muted-foreground font-mono mt-2 truncate">
+                    {operation.line || 'Starting...'}
+                  </div>
+                )}
+
+                {hookLog.length > 0 && (
+                  <pre className="text-xs font-mono bg-muted rounded p-2 mt-2 max-h-48 overflow-auto whitespace-pre-wrap">
+                    {hookLog.join('\n')}
+                  </pre>
+                )}
+
+                {error && <div className="text-xs text-red-500 mt-2 whitespace-pre-line">{error}</div>}
               </div>
 
               <div className="flex gap-2 mt-6">
-                <button type="button" className="btn-secondary" onClick={onBack}>
-                  Cancel
-                </button>
+                {operation ? (
+                  <button type="button" className="btn-secondary" onClick={() => api.cancelOperation(operation.id)}>
+                    Stop
+                  </button>
+                ) : runningHooks ? (
+                  <button
+                    type="button"
+                    className="btn-secondary"
+                    onClick={() => api.cancelWorktreeHooks(worktreePath.trim())}
+                  >
+                    Stop hooks
+                  </button>
+                ) : (
+                  <button type="button" className="btn-secondary" onClick={onBack}>
+                    Cancel
+                  </button>
+                )}
                 <button
                   type="submit"
                   className="btn-primary"
                   disabled={loading || !branchName.trim() || !worktreePath.trim()}
                 >
-                  {loading ? 'Creating...' : 'Create'}
+                  {runningHooks ? 'Running hooks...' : loading ? 'Creating...' : 'Create'}
                 </button>
               </div>
             </form>
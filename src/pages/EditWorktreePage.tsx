@@ -70,7 +70,7 @@ export function EditWorktreePage({ worktree, onBack, onSaved }: EditWorktreePage
       onSaved();
       onBack();
     } catch (err) {
-      setError(err instanceof Error ? err.message : String(err));
+      setError(api.getErrorMessage(err));
     } finally {
       setSaving(false);
     }
@@ -98,12 +98,13 @@ export function EditWorktreePage({ worktree, onBack, onSaved }: EditWorktreePage
     });
 
     try {
+      await api.runPreRemoveHooks(repoPath, worktree.path, worktree.branch, worktree.issueNumber, force);
       await api.removeWorktree(repoPath, worktree.path, force, deleteBranchToo, worktree.branch);
       onSaved();
       onBack();
     } catch (err) {
       console.error('Failed to delete worktree:', err);
-      const errorMessage = err instanceof Error ? err.message : String(err);
+      const errorMessage = api.getErrorMessage(err);
 
       if (!force) {
         // Close the delete modal and offer force delete
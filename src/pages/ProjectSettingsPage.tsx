@@ -21,14 +21,48 @@ export function ProjectSettingsPage({ project, onBack, onDeleted, onSaved }: Pro
   const [name, setName] = useState(project.name);
   const [defaultBaseBranch, setDefaultBaseBranch] = useState(project.defaultBaseBranch || '');
   const [ideOverride, setIdeOverride] = useState(project.ide || '');
+  const [config, setConfig] = useState<api.BackendProjectConfig | null>(null);
+  const [repoConfig, setRepoConfig] = useState<api.RepoConfig | null>(null);
+  const [templateOverride, setTemplateOverride] = useState('');
+  const [fetchOverride, setFetchOverride] = useState('');
+  const [copyPathsOverride, setCopyPathsOverride] = useState('');
+  const [patternsOverride, setPatternsOverride] = useState('');
+  const [postCreateHooks, setPostCreateHooks] = useState('');
+  const [preRemoveHooks, setPreRemoveHooks] = useState('');
 
   // Delete modal state
   const [deleteModalOpen, setDeleteModalOpen] = useState(false);
 
   useEffect(() => {
     loadBranches();
+    loadConfig();
   }, [project.repoPath]);
 
+  const loadConfig = async () => {
+    try {
+      // Edit the local settings only; `.grovr.toml` values are shown as placeholders
+      const { local: current, repo } = await api.getProjectConfig(project.repoPath);
+      setConfig(current);
+      setRepoConfig(repo);
+      setDefaultBaseBranch(current.default_base_branch || '');
+      setIdeOverride(current.ide?.preset || '');
+      setTemplateOverride(current.worktree_template || '');
+      setFetchOverride(current.fetch_before_create == null ? '' : String(current.fetch_before_create));
+      setCopyPathsOverride((current.copy_paths || []).map(api.formatCopyPath).join('\n'));
+      setPatternsOverride((current.clipboard_parse_patterns || []).join('\n'));
+      setPostCreateHooks((current.hooks?.post_create || []).join('\n'));
+      setPreRemoveHooks((current.hooks?.pre_remove || []).join('\n'));
+    } catch (err) {
+      console.error('Failed to load project config:', err);
+    }
+  };
+
+  // One entry per line; an empty list means "use the global setting"
+  const parseLines = (value: string) => {
+    const lines = value.split('\n').map((l) => l.trim()).filter(Boolean);
+    return lines.length > 0 ? lines : undefined;
+  };
+
   const loadBranches = async () => {
     try {
       const branchList = await api.getBranches(project.repoPath, true);
@@ -41,11 +75,24 @@ export function ProjectSettingsPage({ project, onBack, onDeleted, onSaved }: Pro
   const handleSave = async () => {
     setSaving(true);
     try {
+      const postCreate = parseLines(postCreateHooks) || [];
+      const preRemove = parseLines(preRemoveHooks) || [];
+      const hooks =
+        postCreate.length > 0 || preRemove.length > 0
+          ? { post_create: postCreate, pre_remove: preRemove, timeout_secs: config?.hooks?.timeout_secs }
+          : undefined;
       const updatedProject: api.BackendProjectConfig = {
+        // Keep fields this page does not edit
+        ...config,
         name: name.trim(),
         repo_path: project.repoPath,
         default_base_branch: defaultBaseBranch || undefined,
         ide: ideOverride ? { type: 'preset', preset: ideOverride } : undefined,
+        worktree_template: templateOverride.trim() || undefined,
+        fetch_before_create: fetchOverride === '' ? undefined : fetchOverride === 'true',
+        copy_paths: parseLines(copyPathsOverride)?.map(api.parseCopyPath),
+        clipboard_parse_patterns: parseLines(patternsOverride),
+        hooks,
       };
       await api.updateProject(project.repoPath, updatedProject);
       onSaved();
@@ -92,6 +139,12 @@ export function ProjectSettingsPage({ project, onBack, onDeleted, onSaved }: Pro
           <div className="project-settings-content">
           <h1 className="project-settings-title">{project.name}</h1>
 
+          {repoConfig && (
+            <p className="settings-hint mb-4">
+              This repository ships a <code>.grovr.toml</code>. Its values apply wherever a field below is left empty.
+            </p>
+          )}
+
           <div className="settings-group">
             {/* Name */}
             <div className="settings-item-full">
@@ -123,7 +176,9 @@ export function ProjectSettingsPage({ project, onBack, onDeleted, onSaved }: Pro
                 value={defaultBaseBranch}
                 onChange={(e) => setDefaultBaseBranch(e.target.value)}
               >
-                <option value="">Select branch...</option>
+                <option value="">
+                  {repoConfig?.default_base_branch ? `${repoConfig.default_base_branch} (.grovr.toml)` : 'Select branch...'}
+                </option>
                 {branches.map((branch) => (
                   <option key={branch} value={branch}>
                     {branch}
@@ -141,7 +196,7 @@ export function ProjectSettingsPage({ project, onBack, onDeleted, onSaved }: Pro
                 value={ideOverride}
                 onChange={(e) => setIdeOverride(e.target.value)}
               >
-                <option value="">Use Default</option>
+                <option value="">{repoConfig?.ide ? `${repoConfig.ide} (.grovr.toml)` : 'Use Default'}</option>
                 {ideOptions.map((ide) => (
                   <option key={ide.id} value={ide.id}>
                     {ide.name}
@@ -150,6 +205,96 @@ export function ProjectSettingsPage({ project, onBack, onDeleted, onSaved }: Pro
               </select>
             </div>
 
+            {/* Worktree Path Template Override */}
+            <div className="settings-item-full">
+              <label className="settings-label">Path Template (Project Override)</label>
+              <p className="settings-hint mb-2">Leave empty to use the global template</p>
+              <input
+                type="text"
+                className="settings-input font-mono text-xs"
+                value={templateOverride}
+                onChange={(e) => setTemplateOverride(e.target.value)}
+                placeholder={repoConfig?.worktree_template || '{project}.worktrees/{branch}-{description}'}
+              />
+            </div>
+
+            {/* Fetch Before Create Override */}
+            <div className="settings-item-full">
+              <label className="settings-label">Fetch before create (Project Override)</label>
+              <select
+                className="settings-select w-full"
+                value={fetchOverride}
+                onChange={(e) => setFetchOverride(e.target.value)}
+              >
+                <option value="">
+                  {repoConfig?.fetch_before_create != null
+                    ? `${repoConfig.fetch_before_create ? 'On' : 'Off'} (.grovr.toml)`
+                    : 'Use Default'}
+                </option>
+                <option value="true">On</option>
+                <option value="false">Off</option>
+              </select>
+            </div>
+
+            {/* Copy Paths Override */}
+            <div className="settings-item-full">
+              <label className="settings-label">Copy on create (Project Override)</label>
+              <p className="settings-hint mb-2">
+                One path or glob per line, <code>!pattern</code> to exclude, <code>(symlink)</code> after a path to link
+                it. Leave empty to use the global list
+              </p>
+              <textarea
+                className="settings-input font-mono text-xs"
+                rows={3}
+                value={copyPathsOverride}
+                onChange={(e) => setCopyPathsOverride(e.target.value)}
+                placeholder={
+                  repoConfig?.copy_paths?.map(api.formatCopyPath).join('\n') || '**/.env*\nnode_modules (symlink)'
+                }
+              />
+            </div>
+
+            {/* Clipboard Patterns Override */}
+            <div className="settings-item-full">
+              <label className="settings-label">Clipboard patterns (Project Override)</label>
+              <p className="settings-hint mb-2">One regex per line. Leave empty to use the global patterns</p>
+              <textarea
+                className="settings-input font-mono text-xs"
+                rows={2}
+                value={patternsOverride}
+                onChange={(e) => setPatternsOverride(e.target.value)}
+                placeholder={repoConfig?.clipboard_parse_patterns?.join('\n') || '(?<issueNumber>...)'}
+              />
+            </div>
+
+            {/* Hooks */}
+            <div className="settings-item-full">
+              <label className="settings-label">Post-create hooks</label>
+              <p className="settings-hint mb-2">
+                Shell commands run in the new worktree, one per line. <code>GROVR_WORKTREE_PATH</code>,{' '}
+                <code>GROVR_BRANCH</code>, <code>GROVR_BASE_BRANCH</code> and <code>GROVR_ISSUE</code> are set
+              </p>
+              <textarea
+                className="settings-input font-mono text-xs"
+                rows={3}
+                value={postCreateHooks}
+                onChange={(e) => setPostCreateHooks(e.target.value)}
+                placeholder={repoConfig?.hooks?.post_create.join('\n') || 'pnpm install\ndirenv allow'}
+              />
+            </div>
+
+            <div className="settings-item-full">
+              <label className="settings-label">Pre-remove hooks</label>
+              <p className="settings-hint mb-2">Run in the worktree before it is deleted. A failing hook stops the delete</p>
+              <textarea
+                className="settings-input font-mono text-xs"
+                rows={2}
+                value={preRemoveHooks}
+                onChange={(e) => setPreRemoveHooks(e.target.value)}
+                placeholder={repoConfig?.hooks?.pre_remove.join('\n') || 'docker compose down'}
+              />
+            </div>
+
             {/* Action Buttons */}
             <div className="flex gap-2 mt-4">
               <button type="button" className="btn-secondary" onClick={onBack}>
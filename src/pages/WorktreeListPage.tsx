@@ -386,6 +386,12 @@ export function WorktreeListPage({
                   path: w.path,
                   branch: w.branch,
                   isMain: w.is_main,
+                  isDetached: w.is_detached,
+                  head: w.head,
+                  isLocked: w.is_locked,
+                  lockReason: w.lock_reason,
+                  isPrunable: w.is_prunable,
+                  prunableReason: w.prunable_reason,
                 };
 
                 // Load memo (local data)
@@ -466,6 +472,29 @@ export function WorktreeListPage({
     loadData();
   }, []);
 
+  // Apply changes pushed by the backend watcher (e.g. branches switched from a terminal)
+  useEffect(() => {
+    const unlisten = api.onWorktreeEvents((event, payload) => {
+      if (event !== 'worktree-changed' || !payload.worktree) {
+        loadData();
+        return;
+      }
+      const w = payload.worktree;
+      updateWorktree(payload.repo_path, payload.path, {
+        branch: w.branch,
+        isDetached: w.is_detached,
+        head: w.head,
+        isLocked: w.is_locked,
+        lockReason: w.lock_reason,
+        isPrunable: w.is_prunable,
+        prunableReason: w.prunable_reason,
+      });
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [updateWorktree]);
+
   const toggleProject = (repoPath: string) => {
     const next = new Set(expandedProjects);
     if (next.has(repoPath)) {
@@ -481,7 +510,7 @@ export function WorktreeListPage({
       await api.openIde(path, preset, customCommand);
     } catch (err) {
       console.error('Failed to open IDE:', err);
-      const errorMessage = err instanceof Error ? err.message : String(err);
+      const errorMessage = api.getErrorMessage(err);
       const ideInfo = getIDEInfo(preset);
       await message(
         `Failed to open ${ideInfo.name}.\n\nMake sure the IDE is installed and the command is available in your PATH.\n\nError: ${errorMessage}`,
@@ -578,6 +607,7 @@ export function WorktreeListPage({
     });
 
     try {
+      await api.runPreRemoveHooks(repoPath, worktree.path, worktree.branch, worktree.issueNumber, force);
       await api.removeWorktree(repoPath, worktree.path, force, deleteBranchToo, worktree.branch);
       setDeleteModalOpen(false);
       setForceDeleteModalOpen(false);
@@ -586,7 +616,7 @@ export function WorktreeListPage({
       loadData();
     } catch (err) {
       console.error('Failed to delete worktree:', err);
-      const errorMessage = err instanceof Error ? err.message : String(err);
+      const errorMessage = api.getErrorMessage(err);
 
       if (!force) {
         // If normal delete fails, offer force delete
@@ -1106,8 +1136,16 @@ function WorktreeRow({
       {/* Branch */}
       <div className="worktree-col-branch">
         <GitBranch size={14} className="worktree-branch-icon" />
-        <span className="worktree-branch-name">{worktree.branch}</span>
+        <span className="worktree-branch-name">
+          {worktree.isDetached ? `detached @ ${worktree.head?.slice(0, 7) ?? ''}` : worktree.branch}
+        </span>
         {worktree.isMain && <span className="worktree-main-badge">main</span>}
+        {worktree.isLocked && (
+          <span className="worktree-main-badge" title={worktree.lockReason}>locked</span>
+        )}
+        {worktree.isPrunable && (
+          <span className="worktree-main-badge" title={worktree.prunableReason}>stale</span>
+        )}
       </div>
 
       {/* Description - only show if any worktree has description */}
@@ -74,7 +74,7 @@ export function GitHubSettings() {
         setTestResult({ valid: false, message: result.error || 'Invalid token' });
       }
     } catch (err) {
-      setTestResult({ valid: false, message: err instanceof Error ? err.message : String(err) });
+      setTestResult({ valid: false, message: api.getErrorMessage(err) });
     } finally {
       setTesting(false);
     }
@@ -109,7 +109,7 @@ export function GitHubSettings() {
       setShowForm(false);
       resetForm();
     } catch (err) {
-      setError(err instanceof Error ? err.message : String(err));
+      setError(api.getErrorMessage(err));
     } finally {
       setSaving(false);
     }
@@ -120,7 +120,7 @@ export function GitHubSettings() {
       await api.removeGitHubConfig();
       setConfig(null);
     } catch (err) {
-      setError(err instanceof Error ? err.message : String(err));
+      setError(api.getErrorMessage(err));
     }
   };
 
@@ -76,7 +76,7 @@ export function JiraSettings() {
       await api.setJiraConfig(newConfig);
       setConfig({ ...config, host });
     } catch (err) {
-      setError(err instanceof Error ? err.message : String(err));
+      setError(api.getErrorMessage(err));
     } finally {
       setSavingHost(false);
     }
@@ -104,7 +104,7 @@ export function JiraSettings() {
         setTestResult({ valid: false, message: result.error || 'Invalid credentials' });
       }
     } catch (err) {
-      setTestResult({ valid: false, message: err instanceof Error ? err.message : String(err) });
+      setTestResult({ valid: false, message: api.getErrorMessage(err) });
     } finally {
       setTesting(false);
     }
@@ -153,7 +153,7 @@ export function JiraSettings() {
       setTestResult(null);
     } catch (err) {
       console.error('[Jira Save] Error:', err);
-      setError(err instanceof Error ? err.message : String(err));
+      setError(api.getErrorMessage(err));
     } finally {
       setSavingCredentials(false);
     }
@@ -168,7 +168,7 @@ export function JiraSettings() {
       await api.setJiraConfig(newConfig);
       setConfig({ host: config.host, has_token: false });
     } catch (err) {
-      setError(err instanceof Error ? err.message : String(err));
+      setError(api.getErrorMessage(err));
     }
   };
 
@@ -7,7 +7,7 @@ const DEFAULT_PATTERN = '\\[(?<issueNumber>[A-Z]+-\\d+)\\]\\s*(?<description>.+)
 export function WorktreeSettings() {
   const [pathTemplate, setPathTemplate] = useState('{project}.worktrees/{branch}-{description}');
   const [fetchBeforeCreate, setFetchBeforeCreate] = useState(true);
-  const [copyPaths, setCopyPaths] = useState<string[]>([]);
+  const [copyPaths, setCopyPaths] = useState<api.CopyPathSpec[]>([]);
   const [newCopyPath, setNewCopyPath] = useState('');
   const [clipboardPatterns, setClipboardPatterns] = useState<string[]>([]);
   const [newPattern, setNewPattern] = useState('');
@@ -55,8 +55,10 @@ export function WorktreeSettings() {
   };
 
   const addCopyPath = async () => {
-    if (newCopyPath.trim() && !copyPaths.includes(newCopyPath.trim())) {
-      const newPaths = [...copyPaths, newCopyPath.trim()];
+    const spec = api.parseCopyPath(newCopyPath);
+    const label = api.formatCopyPath(spec);
+    if (newCopyPath.trim() && !copyPaths.some((p) => api.formatCopyPath(p) === label)) {
+      const newPaths = [...copyPaths, spec];
       setCopyPaths(newPaths);
       setNewCopyPath('');
       try {
@@ -68,7 +70,7 @@ export function WorktreeSettings() {
     }
   };
 
-  const removeCopyPath = async (path: string) => {
+  const removeCopyPath = async (path: api.CopyPathSpec) => {
     const newPaths = copyPaths.filter((p) => p !== path);
     setCopyPaths(newPaths);
     try {
@@ -126,7 +128,9 @@ export function WorktreeSettings() {
         <div className="settings-item-full">
           <label className="settings-label">Path Template</label>
           <p className="settings-hint">
-            Variables: <code>{'{project}'}</code>, <code>{'{branch}'}</code>, <code>{'{description}'}</code>
+            Variables: <code>{'{project}'}</code>, <code>{'{repo_parent}'}</code>, <code>{'{branch}'}</code>,{' '}
+            <code>{'{description}'}</code>, <code>{'{issue}'}</code>, <code>{'{user}'}</code>, <code>{'{date}'}</code>.
+            Prefix with <code>slug:</code> for a lowercase slug, e.g. <code>{'{slug:description}'}</code>
           </p>
           <input
             type="text"
@@ -153,11 +157,14 @@ export function WorktreeSettings() {
 
         <div className="settings-item-full">
           <label className="settings-label">Copy on create</label>
-          <p className="settings-hint">Files to copy from main repo</p>
+          <p className="settings-hint">
+            Files or globs to copy from main repo. <code>!pattern</code> excludes, <code>(symlink)</code>,{' '}
+            <code>(hardlink)</code> or <code>(reflink)</code> after a path changes how it is copied
+          </p>
           <div className="copy-paths-list mt-1.5">
             {copyPaths.map((path) => (
-              <div key={path} className="copy-path-item">
-                <code>{path}</code>
+              <div key={api.formatCopyPath(path)} className="copy-path-item">
+                <code>{api.formatCopyPath(path)}</code>
                 <button
                   className="copy-path-remove"
                   onClick={() => removeCopyPath(path)}
@@ -171,7 +178,7 @@ export function WorktreeSettings() {
             <input
               type="text"
               className="settings-input"
-              placeholder=".env or config/"
+              placeholder="**/.env* or node_modules (symlink)"
               value={newCopyPath}
               onChange={(e) => setNewCopyPath(e.target.value)}
               onKeyDown={(e) => e.key === 'Enter' && addCopyPath()}
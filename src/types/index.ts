@@ -3,6 +3,12 @@ export interface Worktree {
   path: string;
   branch: string;
   isMain: boolean;
+  isDetached?: boolean;
+  head?: string;
+  isLocked?: boolean;
+  lockReason?: string;
+  isPrunable?: boolean;
+  prunableReason?: string;
   description?: string;
   issueNumber?: string;
   repoPath?: string; // Added for edit context